@@ -0,0 +1,144 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::input::{self, INPUT_DIR};
+
+/// Known answers for one set of inputs, so everyone can check their own results. The file lives
+/// next to the inputs it belongs to and looks like:
+///
+/// ```toml
+/// [day1]
+/// part_one = 72511
+/// part_two = 212117
+///
+/// [day5]
+/// part_one = "FJSRQCFTN"
+/// ```
+///
+/// Days and parts that are left out are reported as [`Verdict::Unknown`].
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers(HashMap<String, DayAnswers>);
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DayAnswers {
+    part_one: Option<Expected>,
+    part_two: Option<Expected>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    Unknown,
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Self> {
+        Ok(toml::from_str(input)?)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let input = fs::read_to_string(path)
+            .with_context(|| format!("Could not read the answers from {}", path.display()))?;
+        Self::parse(&input)
+            .with_context(|| format!("{} is not a valid answers file", path.display()))
+    }
+
+    /// Loads [`default_path`], treating a missing file as not knowing any answers.
+    pub fn load_default() -> Result<Self> {
+        match input::find(&default_path()) {
+            Some(path) => Self::load(&path),
+            None => Ok(Answers::default()),
+        }
+    }
+
+    pub fn expected(&self, day: u8, part: u8) -> Option<String> {
+        let answers = self.0.get(&format!("day{day}"))?;
+        let expected = match part {
+            1 => answers.part_one.as_ref()?,
+            2 => answers.part_two.as_ref()?,
+            _ => return None,
+        };
+
+        Some(match expected {
+            Expected::Number(number) => number.to_string(),
+            Expected::Text(text) => text.to_owned(),
+        })
+    }
+
+    /// Compares an answer with the expected one, ignoring trailing whitespace so multi-line
+    /// answers can be written naturally in the file.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Verdict {
+        match self.expected(day, part) {
+            Some(expected) if expected.trim_end() == answer.trim_end() => Verdict::Correct,
+            Some(_) => Verdict::Incorrect,
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+/// The conventional location of the answers file, relative to the workspace root.
+pub fn default_path() -> PathBuf {
+    Path::new(INPUT_DIR).join("answers.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day1]
+part_one = 72511
+part_two = 212117
+
+[day5]
+part_one = "FJSRQCFTN"
+
+[day10]
+part_two = """
+##..
+..##
+"""
+"#;
+
+    #[test]
+    fn test_check() -> Result<()> {
+        let answers = Answers::parse(ANSWERS)?;
+        assert_eq!(answers.check(1, 1, "72511"), Verdict::Correct);
+        assert_eq!(answers.check(1, 2, "212118"), Verdict::Incorrect);
+        assert_eq!(answers.check(5, 1, "FJSRQCFTN"), Verdict::Correct);
+        assert_eq!(answers.check(5, 2, "CJVLJQPHS"), Verdict::Unknown);
+        assert_eq!(answers.check(7, 1, "1182909"), Verdict::Unknown);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_multi_line() -> Result<()> {
+        let answers = Answers::parse(ANSWERS)?;
+        assert_eq!(answers.check(10, 2, "##..\n..##\n"), Verdict::Correct);
+        assert_eq!(answers.check(10, 2, "##..\n..##"), Verdict::Correct);
+        assert_eq!(answers.check(10, 2, "##..\n.###\n"), Verdict::Incorrect);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_part() {
+        assert!(Answers::parse("[day1]\npart1 = 72511\n").is_err());
+    }
+}
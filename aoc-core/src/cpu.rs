@@ -0,0 +1,53 @@
+//! The CPU time of the current thread, which unlike the wall-clock leaves out the time the thread
+//! spends waiting, e.g. for its turn on a busy CPU.
+
+use std::time::Duration;
+
+/// The CPU time the current thread has used since it started, or `None` where that can't be
+/// measured.
+#[cfg(unix)]
+pub fn thread_time() -> Option<Duration> {
+    // SAFETY: An all-zero `timespec` is valid, and the call only writes to the one it's given.
+    let mut time: libc::timespec = unsafe { std::mem::zeroed() };
+    let result = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) };
+
+    (result == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+#[cfg(not(unix))]
+pub fn thread_time() -> Option<Duration> {
+    None
+}
+
+/// Runs `f` on the current thread, returning the CPU time it took along with its result.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (Option<Duration>, T) {
+    let start = thread_time();
+    let result = f();
+    let took = thread_time()
+        .zip(start)
+        .map(|(end, start)| end.saturating_sub(start));
+
+    (took, result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::hint::black_box;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_measure() {
+        let (took, sum) = measure(|| (0..1_000_000u64).map(black_box).sum::<u64>());
+        assert_eq!(sum, 499_999_500_000);
+        assert!(took.is_some_and(|took| took > Duration::ZERO));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sleeping() {
+        // Waiting doesn't use the CPU.
+        let (took, ()) = measure(|| std::thread::sleep(Duration::from_millis(50)));
+        assert!(took.is_some_and(|took| took < Duration::from_millis(25)));
+    }
+}
@@ -0,0 +1,93 @@
+//! Made-up puzzle inputs, to load test the solutions with more and bigger inputs than the one
+//! each day comes with.
+
+use oorandom::Rand64;
+use std::ops::RangeInclusive;
+
+use crate::Solution;
+
+/// A day that can make up inputs of its own, in the puzzle's text format.
+pub trait Generate: Solution {
+    /// A valid input that the day's solution answers both parts of. The same `seed` always gives
+    /// the same input, and `size` scales it; what it counts is up to the day, like the number of
+    /// elves or of sensors.
+    fn generate(seed: u64, size: usize) -> String;
+}
+
+/// The random numbers generators draw from. Seeded explicitly and the same on every platform, so
+/// a seed names an input.
+pub struct Rng(Rand64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(Rand64::new(seed.into()))
+    }
+
+    /// A number in `range`, of any integer type.
+    pub fn range<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryInto<i128> + TryFrom<i128>,
+    {
+        let wide = |n: T| n.try_into().unwrap_or_else(|_| unreachable!());
+        let (start, end) = (wide(*range.start()), wide(*range.end()));
+        assert!(start <= end, "cannot pick a number from an empty range");
+
+        let span = end - start + 1;
+        let offset = match u64::try_from(span) {
+            Ok(span) => self.0.rand_range(0..span),
+            Err(_) => self.0.rand_u64(),
+        };
+        T::try_from(start + offset as i128).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// Whether something with the given `probability` happens.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        self.0.rand_float() < probability
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let mut rng = Rng::new(7);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.range(-3i32..=3)));
+            assert_eq!(rng.range(5usize..=5), 5);
+        }
+        rng.range(0..=u64::MAX);
+        rng.range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn test_seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..10).map(|_| rng.range(0..=100)).collect::<Vec<u32>>()
+        };
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut items = (0..20).collect::<Vec<u8>>();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<u8>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<u8>>());
+    }
+}
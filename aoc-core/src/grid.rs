@@ -0,0 +1,388 @@
+use anyhow::{Error, Result};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+};
+
+/// A position in a grid as `(x, y)`, with `(0, 0)` in the top left corner.
+pub type Point = (usize, usize);
+
+const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// A rectangular grid stored densely in row-major order.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from its rows, which all need to be of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::msg(format!(
+                "Row {} has {} cells where the first row has {width}",
+                y + 1,
+                rows[y].len()
+            )));
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses a grid of characters, one row per line, converting every character with `cell`.
+    /// Characters `cell` rejects are reported by their line and column.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self>
+    where
+        F: FnMut(char) -> Option<T>,
+    {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        cell(c).ok_or_else(|| {
+                            Error::msg(format!(
+                                "Unexpected {c:?} at line {}, column {}",
+                                y + 1,
+                                x + 1
+                            ))
+                        })
+                    })
+                    .collect::<Result<Vec<T>>>()
+            })
+            .collect::<Result<Vec<Vec<T>>>>()?;
+        if rows.is_empty() || rows[0].is_empty() {
+            return Err(Error::msg("The grid is empty"));
+        }
+
+        Self::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): Point) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.1 * self.width + point.0])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, cell)| ((i % width, i / width), cell))
+    }
+
+    /// The positions of all cells matching `predicate`, row by row.
+    pub fn positions<F>(&self, mut predicate: F) -> impl Iterator<Item = Point>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .filter_map(move |(point, cell)| predicate(cell).then_some(point))
+    }
+
+    /// The orthogonal neighbours of `point` that lie within the grid.
+    pub fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &NEIGHBOURS_4)
+    }
+
+    /// The orthogonal and diagonal neighbours of `point` that lie within the grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        self.offsets(point, &NEIGHBOURS_8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (x, y): Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let point = (x.checked_add_signed(*dx)?, y.checked_add_signed(*dy)?);
+            self.contains(point).then_some(point)
+        })
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &Self::Output {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut Self::Output {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of the grid"))
+    }
+}
+
+/// Renders every cell with its own `Display`, one line per row.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A grid that only stores the cells that are set, for large or unbounded areas with few cells
+/// of interest.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.cells.get(&point)
+    }
+
+    /// Sets the cell at `point`, returning what was there before.
+    pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        self.cells.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point) -> Option<T> {
+        self.cells.remove(&point)
+    }
+
+    /// The cells that are set with their positions, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.cells.iter().map(|(point, cell)| (*point, cell))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    /// The top left and bottom right corners of the smallest rectangle holding every set cell.
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(min, max), &(x, y)| {
+            ((min.0.min(x), min.1.min(y)), (max.0.max(x), max.1.max(y)))
+        }))
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Renders the bounding rectangle of the set cells, with `.` for the cells that aren't set.
+impl<T: Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((min, max)) = self.bounds() else {
+            return Ok(());
+        };
+        for y in min.1..=max.1 {
+            for x in min.0..=max.0 {
+                match self.get((x, y)) {
+                    Some(cell) => write!(f, "{cell}")?,
+                    None => write!(f, ".")?,
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGITS: &str = "123\n456\n";
+
+    fn digits() -> Result<Grid<u32>> {
+        Grid::parse(DIGITS, |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let grid = digits()?;
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 6);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), DIGITS);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse("123\n4x6\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error.to_string(), "Unexpected 'x' at line 2, column 2");
+
+        let error = Grid::parse("123\n45\n", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Row 2 has 2 cells where the first row has 3"
+        );
+
+        assert!(Grid::parse("", |c| c.to_digit(10)).is_err());
+    }
+
+    #[test]
+    fn test_views() -> Result<()> {
+        let grid = digits()?;
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<u32>>(), vec![2, 5]);
+        assert_eq!(
+            grid.column(2).rev().copied().collect::<Vec<u32>>(),
+            vec![6, 3]
+        );
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(
+            grid.positions(|d| d % 2 == 0).collect::<Vec<Point>>(),
+            vec![(1, 0), (0, 1), (2, 1)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_neighbours() -> Result<()> {
+        let grid = digits()?;
+        assert_eq!(
+            grid.neighbours((0, 0)).collect::<Vec<Point>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours((1, 1)).collect::<Vec<Point>>(),
+            vec![(1, 0), (0, 1), (2, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 1)).collect::<Vec<Point>>(),
+            vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse() {
+        let mut grid = [((500, 4), '#'), ((498, 6), '#')]
+            .into_iter()
+            .collect::<SparseGrid<char>>();
+        grid.insert((500, 6), 'o');
+        assert_eq!(grid.len(), 3);
+        assert_eq!(grid.bounds(), Some(((498, 4), (500, 6))));
+        assert_eq!(grid.to_string(), "..#\n...\n#.o\n");
+        assert_eq!(SparseGrid::<char>::new().bounds(), None);
+    }
+}
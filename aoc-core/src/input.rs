@@ -0,0 +1,158 @@
+use anyhow::{Context, Error, Result};
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::Solution;
+
+/// The directory puzzle inputs are looked up in when no path is given, as `inputs/dayN.txt`.
+pub const INPUT_DIR: &str = "inputs";
+
+/// Loads the puzzle input for `S`.
+///
+/// `path` is taken from the command line: `-` reads standard input and anything else is read as a
+/// file. Without a path the input embedded by the `embed-input` feature is used, falling back to
+/// [`default_path`] relative to the current directory and then to the workspace root.
+///
+/// The input is leaked so days can keep borrowing from it; it lives for the rest of the program
+/// anyway.
+pub fn load<S: Solution>(path: Option<&str>) -> Result<&'static str> {
+    let input = match path {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input).with_context(|| {
+                format!("Could not read the input for day {} from stdin", S::DAY)
+            })?;
+            input
+        }
+        Some(path) => read(S::DAY, Path::new(path))?,
+        None => match S::EMBEDDED {
+            Some(embedded) => return Ok(embedded),
+            None => read(S::DAY, &locate(S::DAY)?)?,
+        },
+    };
+
+    Ok(input.leak())
+}
+
+/// The conventional location of a day's input, relative to the workspace root.
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(INPUT_DIR).join(format!("day{day}.txt"))
+}
+
+/// Finds the file at `relative` from the current directory or, failing that, the workspace root.
+pub fn find(relative: &Path) -> Option<PathBuf> {
+    let candidates = [
+        relative.to_path_buf(),
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(relative),
+    ];
+
+    candidates.into_iter().find(|candidate| candidate.is_file())
+}
+
+fn locate(day: u8) -> Result<PathBuf> {
+    let relative = default_path(day);
+
+    find(&relative).ok_or_else(|| {
+        Error::msg(format!(
+            "There is no input for day {day}: expected it at {}. Pass a path, `-` to read from \
+             stdin, or build with the `embed-input` feature",
+            relative.display()
+        ))
+    })
+}
+
+fn read(day: u8, path: &Path) -> Result<String> {
+    fs::read_to_string(path).with_context(|| {
+        format!(
+            "Could not read the input for day {day} from {}",
+            path.display()
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    struct Missing;
+
+    impl Solution for Missing {
+        const DAY: u8 = 99;
+
+        type Input = ();
+        type PartOne = u8;
+        type PartTwo = u8;
+
+        fn parse(_: &'static str) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part_one(_: &Self::Input) -> Result<Self::PartOne> {
+            Ok(1)
+        }
+
+        fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+            Ok(2)
+        }
+    }
+
+    struct Embedded;
+
+    impl Solution for Embedded {
+        const DAY: u8 = 98;
+        const EMBEDDED: Option<&'static str> = Some("embedded");
+
+        type Input = ();
+        type PartOne = u8;
+        type PartTwo = u8;
+
+        fn parse(_: &'static str) -> Result<Self::Input> {
+            Ok(())
+        }
+
+        fn part_one(_: &Self::Input) -> Result<Self::PartOne> {
+            Ok(1)
+        }
+
+        fn part_two(_: &Self::Input) -> Result<Self::PartTwo> {
+            Ok(2)
+        }
+    }
+
+    #[test]
+    fn test_load_path() -> Result<()> {
+        let path = env::temp_dir().join("aoc-core-test-load-path.txt");
+        fs::write(&path, "1\n2\n3\n")?;
+        assert_eq!(load::<Missing>(path.to_str())?, "1\n2\n3\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_embedded() -> Result<()> {
+        assert_eq!(load::<Embedded>(None)?, "embedded");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_default() {
+        let error = load::<Missing>(None).unwrap_err().to_string();
+        assert!(error.contains("day 99"));
+        assert!(error.contains("day99.txt"));
+    }
+
+    #[test]
+    fn test_load_missing_path() {
+        let error = load::<Missing>(Some("does/not/exist.txt"))
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("does/not/exist.txt"));
+    }
+}
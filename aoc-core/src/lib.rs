@@ -0,0 +1,12 @@
+pub mod answers;
+mod cpu;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod output;
+pub mod parse;
+mod report;
+mod solution;
+
+pub use report::{main, run, Part, Report};
+pub use solution::Solution;
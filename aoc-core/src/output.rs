@@ -0,0 +1,148 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::Write;
+
+use crate::{
+    answers::{Answers, Verdict},
+    Report,
+};
+
+/// How answers and timings are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines or tables
+    #[default]
+    Text,
+    /// A JSON array of records
+    Json,
+    /// CSV records with a header line
+    Csv,
+}
+
+/// One part of one day, flattened for ingestion by other tools. Durations are in nanoseconds and
+/// both parts of a day share the same parse duration. The status says whether the answer matches
+/// the known answers.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    pub status: Verdict,
+}
+
+impl Report {
+    pub fn records(&self, answers: &Answers) -> [Record; 2] {
+        let parse_ns = self.parse.as_std().as_nanos() as u64;
+        [(1, &self.part_one), (2, &self.part_two)].map(|(part, result)| Record {
+            day: self.day,
+            part,
+            answer: result.answer.clone(),
+            parse_ns,
+            solve_ns: result.took.as_std().as_nanos() as u64,
+            status: answers.check(self.day, part, &result.answer),
+        })
+    }
+}
+
+/// Writes the records of `reports` as a JSON array.
+pub fn write_json<W: Write>(reports: &[Report], answers: &Answers, mut out: W) -> Result<()> {
+    let records = reports
+        .iter()
+        .flat_map(|report| report.records(answers))
+        .collect::<Vec<Record>>();
+    serde_json::to_writer_pretty(&mut out, &records)?;
+    writeln!(out)?;
+
+    Ok(())
+}
+
+/// Writes the records of `reports` as CSV, preceded by a header line.
+pub fn write_csv<W: Write>(reports: &[Report], answers: &Answers, out: W) -> Result<()> {
+    let mut writer = csv::Writer::from_writer(out);
+    for record in reports.iter().flat_map(|report| report.records(answers)) {
+        writer.serialize(record)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Part;
+    use std::time::Duration;
+    use took::Took;
+
+    fn report() -> Report {
+        Report {
+            day: 10,
+            parse: Took::from_std(Duration::from_nanos(1_500)),
+            part_one: Part {
+                answer: "13140".to_owned(),
+                took: Took::from_std(Duration::from_nanos(250)),
+            },
+            part_two: Part {
+                answer: "▓░\n░▓\n".to_owned(),
+                took: Took::from_std(Duration::from_micros(2)),
+            },
+            wall: Took::from_std(Duration::from_micros(3)),
+            cpu: Some(Took::from_std(Duration::from_micros(3))),
+        }
+    }
+
+    #[test]
+    fn test_records() -> Result<()> {
+        let answers = Answers::parse("[day10]\npart_one = 13140\npart_two = \"wrong\"\n")?;
+        let [one, two] = report().records(&answers);
+        assert_eq!(
+            one,
+            Record {
+                day: 10,
+                part: 1,
+                answer: "13140".to_owned(),
+                parse_ns: 1_500,
+                solve_ns: 250,
+                status: Verdict::Correct,
+            }
+        );
+        assert_eq!(two.part, 2);
+        assert_eq!(two.parse_ns, 1_500);
+        assert_eq!(two.solve_ns, 2_000);
+        assert_eq!(two.status, Verdict::Incorrect);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_json() -> Result<()> {
+        let mut out = vec![];
+        write_json(&[report()], &Answers::default(), &mut out)?;
+
+        let records: serde_json::Value = serde_json::from_slice(&out)?;
+        assert_eq!(records[0]["answer"], "13140");
+        assert_eq!(records[1]["answer"], "▓░\n░▓\n");
+        assert_eq!(records[1]["solve_ns"], 2_000);
+        assert_eq!(records[1]["status"], "unknown");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_csv() -> Result<()> {
+        let mut out = vec![];
+        write_csv(&[report()], &Answers::default(), &mut out)?;
+
+        assert_eq!(
+            String::from_utf8(out)?,
+            "day,part,answer,parse_ns,solve_ns,status\n\
+             10,1,13140,1500,250,unknown\n\
+             10,2,\"▓░\n░▓\n\",1500,2000,unknown\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,359 @@
+//! Building blocks shared by the day parsers, so each day only has to describe what its own
+//! lines look like.
+//!
+//! The combinators are generic over the nom error type so they compose with whatever error the
+//! day's own parsers use. Day parsers use [`IResult`], whose [`InputError`] keeps the contexts
+//! added with [`nom::error::context`] so [`parse_all`] can explain where and why parsing failed.
+
+use anyhow::{Error, Result};
+use nom::{
+    character::complete::{char, digit1, line_ending, none_of},
+    combinator::{all_consuming, cut, map_opt, map_res, not, opt, recognize},
+    error::{ContextError, ErrorKind, FromExternalError, ParseError},
+    multi::{many1, separated_list1},
+    sequence::{pair, separated_pair, terminated},
+    Offset, Parser,
+};
+use std::{fmt::Display, str::FromStr};
+
+use crate::grid::Grid;
+
+/// The result of the day parsers.
+pub type IResult<I, O> = nom::IResult<I, O, InputError<I>>;
+
+/// Everything nom went through before giving up, from the innermost parser outwards, like nom's
+/// own `VerboseError`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InputError<I> {
+    errors: Vec<(I, Kind)>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Kind {
+    Nom(ErrorKind),
+    Char(char),
+    Context(&'static str),
+    External(String),
+}
+
+impl<I> ParseError<I> for InputError<I> {
+    fn from_error_kind(input: I, kind: ErrorKind) -> Self {
+        InputError {
+            errors: vec![(input, Kind::Nom(kind))],
+        }
+    }
+
+    fn append(input: I, kind: ErrorKind, mut other: Self) -> Self {
+        other.errors.push((input, Kind::Nom(kind)));
+        other
+    }
+
+    fn from_char(input: I, c: char) -> Self {
+        InputError {
+            errors: vec![(input, Kind::Char(c))],
+        }
+    }
+}
+
+impl<I> ContextError<I> for InputError<I> {
+    fn add_context(input: I, context: &'static str, mut other: Self) -> Self {
+        other.errors.push((input, Kind::Context(context)));
+        other
+    }
+}
+
+impl<I, E: Display> FromExternalError<I, E> for InputError<I> {
+    fn from_external_error(input: I, _: ErrorKind, e: E) -> Self {
+        InputError {
+            errors: vec![(input, Kind::External(e.to_string()))],
+        }
+    }
+}
+
+impl InputError<&str> {
+    /// Explains the error in terms of `input`, the text that was being parsed: the line and
+    /// column where parsing failed, what was expected there and the contexts it happened in,
+    /// followed by the offending line with a marker under the position.
+    pub fn describe(&self, input: &str) -> String {
+        let Some((remaining, kind)) = self.errors.first() else {
+            return "Invalid input".to_owned();
+        };
+        let offset = input.offset(remaining);
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_number = input[..offset].matches('\n').count() + 1;
+        let column = input[line_start..offset].chars().count() + 1;
+        let line = input[line_start..].lines().next().unwrap_or_default();
+
+        let mut message = format!(
+            "Invalid input at line {line_number}, column {column}: {}",
+            kind.problem()
+        );
+        for (_, kind) in &self.errors {
+            if let Kind::Context(context) = kind {
+                message.push_str(&format!(" in {context}"));
+            }
+        }
+
+        format!("{message}\n{line}\n{:>column$}", "^")
+    }
+}
+
+impl Kind {
+    fn problem(&self) -> String {
+        match self {
+            Kind::Char(c) => format!("expected {c:?}"),
+            Kind::Context(context) => format!("expected {context}"),
+            Kind::External(message) => message.to_owned(),
+            Kind::Nom(ErrorKind::Eof) => "expected the end of the input".to_owned(),
+            Kind::Nom(ErrorKind::Digit) => "expected a number".to_owned(),
+            Kind::Nom(ErrorKind::Alpha) => "expected a letter".to_owned(),
+            Kind::Nom(ErrorKind::CrLf) => "expected a line ending".to_owned(),
+            Kind::Nom(ErrorKind::Space) => "expected a space".to_owned(),
+            Kind::Nom(ErrorKind::OneOf | ErrorKind::NoneOf | ErrorKind::Not) => {
+                "unexpected character".to_owned()
+            }
+            Kind::Nom(ErrorKind::MapOpt | ErrorKind::MapRes) => "invalid value".to_owned(),
+            Kind::Nom(ErrorKind::Tag) => "unexpected text".to_owned(),
+            Kind::Nom(kind) => format!("unexpected input ({})", kind.description()),
+        }
+    }
+}
+
+/// Runs `parser` over the whole of the [`normalize`]d `input`. Anything that remains is an error
+/// rather than silently ignored, reported from the start of the line it is on.
+pub fn parse_all<O, P>(parser: P, input: &'static str) -> Result<O>
+where
+    P: Parser<&'static str, Output = O, Error = InputError<&'static str>>,
+{
+    let input = normalize(input);
+    match all_consuming(terminated(parser, opt(line_ending))).parse(input) {
+        Ok((_, output)) => Ok(output),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => {
+            Err(Error::msg(error.describe(input)))
+        }
+        Err(nom::Err::Incomplete(_)) => Err(Error::msg("The input ended unexpectedly")),
+    }
+}
+
+/// Irons out the differences editors and platforms introduce without changing what an input
+/// means: CRLF line endings, whitespace at the end of lines and blank lines at the end. Parsers
+/// only ever see `\n` between lines and no line ending after the last one.
+///
+/// Inputs that are already normal are returned as they are; others are copied and leaked, like
+/// loaded inputs are.
+pub fn normalize(input: &'static str) -> &'static str {
+    // Most inputs are normal already, and finding that out doesn't need a copy.
+    let body = input.strip_suffix('\n').unwrap_or(input);
+    if !body.ends_with('\n')
+        && body
+            .split('\n')
+            .all(|line| !line.ends_with(char::is_whitespace))
+    {
+        return body;
+    }
+
+    let normal = input
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n");
+    normal.trim_end_matches('\n').to_owned().leak()
+}
+
+/// The same input as it turns up from different editors and platforms: with CRLF line endings,
+/// without a final newline, with blank lines at the end and with trailing whitespace on every
+/// line. Days use these in tests to check they give the same answers for all of them.
+pub fn variants(input: &str) -> Vec<&'static str> {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    [
+        lines.join("\r\n") + "\r\n",
+        lines.join("\n"),
+        lines.join("\n") + "\n\n\n",
+        lines.join(" \n") + " \t\n",
+    ]
+    .into_iter()
+    .map(|variant| &*variant.leak())
+    .collect()
+}
+
+/// One or more items separated by line endings.
+pub fn lines<'a, O, E, P>(line: P) -> impl Parser<&'a str, Output = Vec<O>, Error = E>
+where
+    E: ParseError<&'a str>,
+    P: Parser<&'a str, Output = O, Error = E>,
+{
+    separated_list1(line_ending, line)
+}
+
+/// One or more blocks of lines separated by blank lines.
+pub fn paragraphs<'a, O, E, P>(paragraph: P) -> impl Parser<&'a str, Output = Vec<O>, Error = E>
+where
+    E: ParseError<&'a str>,
+    P: Parser<&'a str, Output = O, Error = E>,
+{
+    separated_list1(pair(line_ending, line_ending), paragraph)
+}
+
+/// A rectangular block of characters, converting every character with `cell`. A character `cell`
+/// rejects is an error, wherever in a row it appears.
+pub fn char_grid<'a, T, E, F>(cell: F) -> impl Parser<&'a str, Output = Grid<T>, Error = E>
+where
+    E: ParseError<&'a str> + FromExternalError<&'a str, anyhow::Error>,
+    F: Fn(char) -> Option<T> + Copy,
+{
+    let row = terminated(
+        many1(map_opt(none_of("\r\n"), cell)),
+        cut(not(none_of("\r\n"))),
+    );
+
+    map_res(lines(row), Grid::from_rows)
+}
+
+/// A number without a sign, as any integer type.
+pub fn unsigned<'a, O, E>(input: &'a str) -> nom::IResult<&'a str, O, E>
+where
+    O: FromStr,
+    E: ParseError<&'a str>,
+{
+    map_opt(digit1, |digits: &str| digits.parse().ok()).parse(input)
+}
+
+/// A number with an optional leading `-`, as any integer type.
+pub fn signed<'a, O, E>(input: &'a str) -> nom::IResult<&'a str, O, E>
+where
+    O: FromStr,
+    E: ParseError<&'a str>,
+{
+    map_opt(recognize(pair(opt(char('-')), digit1)), |number: &str| {
+        number.parse().ok()
+    })
+    .parse(input)
+}
+
+/// One or more unsigned numbers separated by `separator`.
+pub fn unsigned_list<'a, O, E, S>(separator: S) -> impl Parser<&'a str, Output = Vec<O>, Error = E>
+where
+    O: FromStr,
+    E: ParseError<&'a str>,
+    S: Parser<&'a str, Error = E>,
+{
+    separated_list1(separator, unsigned)
+}
+
+/// One or more signed numbers separated by `separator`.
+pub fn signed_list<'a, O, E, S>(separator: S) -> impl Parser<&'a str, Output = Vec<O>, Error = E>
+where
+    O: FromStr,
+    E: ParseError<&'a str>,
+    S: Parser<&'a str, Error = E>,
+{
+    separated_list1(separator, signed)
+}
+
+/// Two signed numbers separated by `separator`, as `(x, y)`.
+pub fn coordinate<'a, O, E, S>(separator: S) -> impl Parser<&'a str, Output = (O, O), Error = E>
+where
+    O: FromStr,
+    E: ParseError<&'a str>,
+    S: Parser<&'a str, Error = E>,
+{
+    separated_pair(signed, separator, signed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{bytes::complete::tag, error::context};
+
+    #[test]
+    fn test_parse_all() -> Result<()> {
+        let numbers = |input| parse_all(lines(unsigned::<u32, _>), input);
+        assert_eq!(numbers("1\n2\n3")?, vec![1, 2, 3]);
+        assert_eq!(numbers("1\n2\n3\n")?, vec![1, 2, 3]);
+        assert_eq!(numbers("1 \r\n2\t\r\n3\r\n\r\n")?, vec![1, 2, 3]);
+        assert!(numbers("1\n\n2\n").is_err());
+        assert!(numbers("1\n2\nthree\n").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe() {
+        let pairs = |input| {
+            parse_all(
+                lines(context("a pair", coordinate::<u32, _, _>(char(',')))),
+                input,
+            )
+        };
+
+        assert_eq!(
+            pairs("1,x\n").unwrap_err().to_string(),
+            "Invalid input at line 1, column 3: expected a number in a pair\n1,x\n  ^"
+        );
+        assert_eq!(
+            pairs("1,2\n3,x\n").unwrap_err().to_string(),
+            "Invalid input at line 2, column 1: expected the end of the input\n3,x\n^"
+        );
+        assert_eq!(
+            pairs("1,2\n\n3,4\n").unwrap_err().to_string(),
+            "Invalid input at line 2, column 1: expected the end of the input\n\n^"
+        );
+    }
+
+    #[test]
+    fn test_normalize() {
+        let input = "  1\n\n2\n";
+        let normal = "  1\n\n2";
+        assert!(std::ptr::eq(normalize(input), &input[..normal.len()]));
+        assert_eq!(normalize(" 1 \r\n\r\n2\r\n\n"), " 1\n\n2");
+        assert_eq!(normalize("1\n\n"), "1");
+        assert_eq!(normalize("1\n \n"), "1");
+        assert_eq!(normalize("\n"), "");
+        assert_eq!(normalize(""), "");
+        for variant in variants("  1\n\n2\n") {
+            assert_eq!(normalize(variant), normal);
+        }
+    }
+
+    #[test]
+    fn test_paragraphs() -> Result<()> {
+        let input = "1\n2\n\n3\n";
+        let sums = parse_all(paragraphs(unsigned_list::<u32, _, _>(line_ending)), input)?;
+        assert_eq!(sums, vec![vec![1, 2], vec![3]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_numbers() -> Result<()> {
+        assert_eq!(
+            parse_all(signed_list::<i32, _, _>(tag(", ")), "-1, 2, -30")?,
+            vec![-1, 2, -30]
+        );
+        assert!(parse_all(unsigned_list::<u32, _, _>(tag(", ")), "1, -2").is_err());
+        assert!(parse_all(unsigned::<u8, _>, "256").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coordinate() -> Result<()> {
+        assert_eq!(
+            parse_all(coordinate::<i64, _, _>(char(',')), "498,-4")?,
+            (498, -4)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_char_grid() -> Result<()> {
+        let grid = parse_all(char_grid(|c| c.to_digit(10)), "12\n34\n")?;
+        assert_eq!(grid.row(1), &[3, 4]);
+        assert!(parse_all(char_grid(|c| c.to_digit(10)), "12\n3\n").is_err());
+        assert!(parse_all(char_grid(|c| c.to_digit(10)), "12\n3x\n").is_err());
+
+        Ok(())
+    }
+}
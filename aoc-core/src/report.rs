@@ -0,0 +1,196 @@
+use anyhow::{Context, Result};
+use clap::Parser;
+use std::{
+    fmt::Display,
+    io,
+    time::{Duration, Instant},
+};
+use took::Took;
+
+use crate::{
+    answers::Answers,
+    cpu, input,
+    output::{self, Format},
+    Solution,
+};
+
+/// The answers of a single day along with the time spent on each phase.
+pub struct Report {
+    pub day: u8,
+    pub parse: Took,
+    pub part_one: Part,
+    pub part_two: Part,
+    /// From the start of parsing until both parts were answered. The parts run at the same time,
+    /// so this is usually less than the phases add up to.
+    pub wall: Took,
+    /// The CPU time the threads spent on the day together, over all phases. `None` where the
+    /// platform can't measure the CPU time of a thread.
+    pub cpu: Option<Took>,
+}
+
+pub struct Part {
+    pub answer: String,
+    pub took: Took,
+}
+
+/// Parses `data` and runs both parts of `S` on it, timing every phase. The parts run at the same
+/// time, on the current rayon thread pool.
+pub fn run<S: Solution>(data: &'static str) -> Result<Report> {
+    let start = Instant::now();
+    let (parse, (parse_cpu, input)) = took::took(|| cpu::measure(|| S::parse(data)));
+    let input = input.with_context(|| parse_failed::<S>())?;
+
+    let ((part_one, part_one_cpu), (part_two, part_two_cpu)) = rayon::join(
+        || timed(|| S::part_one(&input)),
+        || timed(|| S::part_two(&input)),
+    );
+
+    Ok(Report {
+        day: S::DAY,
+        parse,
+        part_one: part_one.with_context(|| part_failed::<S>("one"))?,
+        part_two: part_two.with_context(|| part_failed::<S>("two"))?,
+        wall: Took::from_std(start.elapsed()),
+        cpu: [parse_cpu, part_one_cpu, part_two_cpu]
+            .into_iter()
+            .sum::<Option<Duration>>()
+            .map(Took::from_std),
+    })
+}
+
+#[derive(Parser)]
+struct Args {
+    /// The puzzle input: a path, or `-` to read stdin. Defaults to `inputs/dayN.txt`
+    input: Option<String>,
+
+    /// How to print the answers and timings
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+/// The `main` of every day's binary: loads the input given on the command line (see
+/// [`input::load`]) and prints the answers in the requested format.
+pub fn main<S: Solution>() -> Result<()> {
+    let args = Args::parse();
+    let data = input::load::<S>(args.input.as_deref())?;
+
+    match args.format {
+        Format::Text => print::<S>(data),
+        Format::Json => {
+            let answers = Answers::load_default()?;
+            output::write_json(&[run::<S>(data)?], &answers, io::stdout().lock())
+        }
+        Format::Csv => {
+            let answers = Answers::load_default()?;
+            output::write_csv(&[run::<S>(data)?], &answers, io::stdout().lock())
+        }
+    }
+}
+
+/// Prints each phase's result and timing as soon as it's known.
+fn print<S: Solution>(data: &'static str) -> Result<()> {
+    let (took, result) = took::took(|| S::parse(data));
+    let input = result.with_context(|| parse_failed::<S>())?;
+    println!("Time spent parsing: {took}");
+
+    let (took, result) = took::took(|| S::part_one(&input));
+    print_answer("one", result.with_context(|| part_failed::<S>("one"))?);
+    println!("Time spent: {took}");
+
+    let (took, result) = took::took(|| S::part_two(&input));
+    print_answer("two", result.with_context(|| part_failed::<S>("two"))?);
+    println!("Time spent: {took}");
+
+    Ok(())
+}
+
+fn parse_failed<S: Solution>() -> String {
+    format!("Could not parse the input for day {}", S::DAY)
+}
+
+fn part_failed<S: Solution>(part: &str) -> String {
+    format!("Could not solve part {part} of day {}", S::DAY)
+}
+
+/// Runs a part, returning its answer and wall-clock time along with the CPU time it took.
+fn timed<T, F>(part: F) -> (Result<Part>, Option<Duration>)
+where
+    T: Display,
+    F: FnOnce() -> Result<T>,
+{
+    let (took, (cpu, answer)) = took::took(|| cpu::measure(part));
+    let part = answer.map(|answer| Part {
+        answer: answer.to_string(),
+        took,
+    });
+
+    (part, cpu)
+}
+
+fn print_answer(part: &str, answer: impl Display) {
+    let answer = answer.to_string();
+    if answer.contains('\n') {
+        print!("Result part {part}:\n{answer}");
+    } else {
+        println!("Result part {part}: {answer}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Error;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u8 = 42;
+
+        type Input = Vec<u32>;
+        type PartOne = u32;
+        type PartTwo = String;
+
+        fn parse(input: &'static str) -> Result<Self::Input> {
+            input
+                .split(',')
+                .map(|n| n.parse::<u32>().map_err(Error::from))
+                .collect()
+        }
+
+        fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+            Ok(input.iter().sum())
+        }
+
+        fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+            input
+                .iter()
+                .max()
+                .map(|max| format!("max {max}"))
+                .ok_or_else(|| Error::msg("There was no maximum"))
+        }
+    }
+
+    #[test]
+    fn test_run() -> Result<()> {
+        let report = run::<Sum>("1,2,3")?;
+        assert_eq!(report.day, 42);
+        assert_eq!(report.part_one.answer, "6");
+        assert_eq!(report.part_two.answer, "max 3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_parse_error() {
+        assert!(run::<Sum>("1,two,3").is_err());
+    }
+
+    #[test]
+    fn test_cpu() -> Result<()> {
+        let report = run::<Sum>("1,2,3")?;
+        assert_eq!(report.cpu.is_some(), cfg!(unix));
+        assert!(report.wall.as_std() >= report.parse.as_std());
+
+        Ok(())
+    }
+}
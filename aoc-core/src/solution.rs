@@ -0,0 +1,26 @@
+use anyhow::Result;
+use std::fmt::Display;
+
+/// The shape every day's puzzle shares: parse the input once, then answer both parts from it.
+///
+/// Parts take the parsed input by reference so they can be run in any order, or more than once,
+/// without having to parse again. Parts that need to mutate the input work on their own copy.
+pub trait Solution {
+    /// The day of the calendar this solves.
+    const DAY: u8;
+
+    /// The puzzle input compiled into the binary, set by days built with the `embed-input`
+    /// feature.
+    const EMBEDDED: Option<&'static str> = None;
+
+    /// Shared by both parts, which may run on different threads at the same time.
+    type Input: Sync;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &'static str) -> Result<Self::Input>;
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne>;
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo>;
+}
@@ -0,0 +1,51 @@
+//! Benchmarks parsing and both parts of every day against its real input.
+//!
+//! Days without an input are skipped. Pass criterion's options after `--`, for example
+//! `cargo bench -p aoc --bench days -- day15/part_two`, or `--save-baseline before` and
+//! `--baseline before` to compare an optimisation against a saved run.
+use aoc_core::{input, Solution};
+use criterion::{criterion_group, criterion_main, Criterion};
+use std::hint::black_box;
+
+fn bench<S: Solution>(c: &mut Criterion) {
+    let data = match input::load::<S>(None) {
+        Ok(data) => data,
+        Err(error) => {
+            eprintln!("Skipping day {}: {error}", S::DAY);
+            return;
+        }
+    };
+    let input = match S::parse(data) {
+        Ok(input) => input,
+        Err(error) => {
+            eprintln!("Skipping day {}: {error}", S::DAY);
+            return;
+        }
+    };
+
+    let mut group = c.benchmark_group(format!("day{}", S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(data))));
+    group.bench_function("part_one", |b| b.iter(|| S::part_one(black_box(&input))));
+    group.bench_function("part_two", |b| b.iter(|| S::part_two(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench::<day1::Day1>,
+    bench::<day2::Day2>,
+    bench::<day3::Day3>,
+    bench::<day4::Day4>,
+    bench::<day5::Day5>,
+    bench::<day6::Day6>,
+    bench::<day7::Day7>,
+    bench::<day8::Day8>,
+    bench::<day9::Day9>,
+    bench::<day10::Day10>,
+    bench::<day11::Day11>,
+    bench::<day12::Day12>,
+    bench::<day13::Day13>,
+    bench::<day14::Day14>,
+    bench::<day15::Day15>,
+);
+criterion_main!(benches);
@@ -0,0 +1,230 @@
+use anyhow::{Error, Result};
+use aoc_core::Report;
+use clap::Args;
+use std::{cmp::Reverse, fmt, str::FromStr, time::Duration};
+use took::Took;
+
+/// A time limit given on the command line, like `1s`, `250ms` or `1.5s`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Budget(Duration);
+
+impl FromStr for Budget {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let invalid = || Error::msg(format!("{s} is not a duration like 1s, 250ms or 500us"));
+
+        let split = s.find(|c: char| c.is_alphabetic()).ok_or_else(invalid)?;
+        let (amount, unit) = s.split_at(split);
+        let amount = amount.trim().parse::<f64>().map_err(|_| invalid())?;
+        let seconds = match unit {
+            "s" => amount,
+            "ms" => amount / 1e3,
+            "us" | "µs" => amount / 1e6,
+            "ns" => amount / 1e9,
+            _ => return Err(invalid()),
+        };
+
+        Duration::try_from_secs_f64(seconds)
+            .map(Budget)
+            .map_err(|_| invalid())
+    }
+}
+
+impl fmt::Display for Budget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", Took::from_std(self.0))
+    }
+}
+
+/// How long a run may take, checked once every day has been answered.
+#[derive(Args)]
+pub struct Budgets {
+    /// Fail if any day spends longer than this parsing or on one of its parts, e.g. `250ms`
+    #[arg(long)]
+    pub part_budget: Option<Budget>,
+
+    /// Fail if all phases of the selected days add up to longer than this, e.g. `1s`
+    #[arg(long)]
+    pub total_budget: Option<Budget>,
+}
+
+impl Budgets {
+    /// Fails with every phase that went over the part budget, and the total if it went over the
+    /// total budget. The total adds up the phases rather than measuring the wall-clock, so it
+    /// doesn't depend on how many threads the days ran on.
+    pub fn check(&self, reports: &[Report]) -> Result<()> {
+        let mut overruns = Vec::new();
+
+        if let Some(budget) = self.part_budget {
+            overruns.extend(
+                phases(reports)
+                    .iter()
+                    .filter(|phase| phase.took > budget.0)
+                    .map(|phase| format!("{phase}, over the part budget of {budget}")),
+            );
+        }
+        if let Some(budget) = self.total_budget {
+            let total = total(reports);
+            if total > budget.0 {
+                overruns.push(format!(
+                    "All days took {} together, over the total budget of {budget}",
+                    Took::from_std(total)
+                ));
+            }
+        }
+
+        if overruns.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::msg(overruns.join("\n")))
+        }
+    }
+}
+
+/// One phase of one day: parsing or one of the parts.
+#[derive(Debug, PartialEq)]
+pub struct Phase {
+    pub day: u8,
+    pub name: &'static str,
+    pub took: Duration,
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Day {} {} took {}",
+            self.day,
+            self.name,
+            Took::from_std(self.took)
+        )
+    }
+}
+
+/// Every phase of the days, slowest first.
+pub fn phases(reports: &[Report]) -> Vec<Phase> {
+    let mut phases = reports
+        .iter()
+        .flat_map(|report| {
+            [
+                ("parsing", &report.parse),
+                ("part one", &report.part_one.took),
+                ("part two", &report.part_two.took),
+            ]
+            .map(|(name, took)| Phase {
+                day: report.day,
+                name,
+                took: *took.as_std(),
+            })
+        })
+        .collect::<Vec<Phase>>();
+    phases.sort_by_key(|phase| Reverse(phase.took));
+
+    phases
+}
+
+/// The time spent on all phases of the days together.
+pub fn total(reports: &[Report]) -> Duration {
+    reports
+        .iter()
+        .map(|report| {
+            *report.parse.as_std() + *report.part_one.took.as_std() + *report.part_two.took.as_std()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Part;
+
+    fn report(day: u8, [parse, part_one, part_two]: [u64; 3]) -> Report {
+        let took = |millis| Took::from_std(Duration::from_millis(millis));
+        let part = |millis| Part {
+            answer: String::new(),
+            took: took(millis),
+        };
+
+        Report {
+            day,
+            parse: took(parse),
+            part_one: part(part_one),
+            part_two: part(part_two),
+            wall: took(parse + part_one.max(part_two)),
+            cpu: None,
+        }
+    }
+
+    fn budget(s: &str) -> Option<Budget> {
+        Some(s.parse().unwrap())
+    }
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        assert_eq!(Budget::from_str("1s")?, Budget(Duration::from_secs(1)));
+        assert_eq!(
+            Budget::from_str("1.5s")?,
+            Budget(Duration::from_millis(1500))
+        );
+        assert_eq!(
+            Budget::from_str("250ms")?,
+            Budget(Duration::from_millis(250))
+        );
+        assert_eq!(
+            Budget::from_str("500us")?,
+            Budget(Duration::from_micros(500))
+        );
+        assert_eq!(Budget::from_str("20ns")?, Budget(Duration::from_nanos(20)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Budget::from_str("1").is_err());
+        assert!(Budget::from_str("s").is_err());
+        assert!(Budget::from_str("1min").is_err());
+        assert!(Budget::from_str("-1s").is_err());
+    }
+
+    #[test]
+    fn test_phases() {
+        let reports = [report(1, [1, 5, 2]), report(2, [3, 4, 6])];
+        let ranked = phases(&reports)
+            .iter()
+            .map(|phase| (phase.day, phase.name))
+            .collect::<Vec<(u8, &str)>>();
+        assert_eq!(
+            ranked,
+            [
+                (2, "part two"),
+                (1, "part one"),
+                (2, "part one"),
+                (2, "parsing"),
+                (1, "part two"),
+                (1, "parsing"),
+            ]
+        );
+        assert_eq!(total(&reports), Duration::from_millis(21));
+    }
+
+    #[test]
+    fn test_check() {
+        let reports = [report(1, [1, 5, 2]), report(2, [3, 4, 6])];
+        let check = |part_budget, total_budget| {
+            Budgets {
+                part_budget,
+                total_budget,
+            }
+            .check(&reports)
+            .map_err(|error| error.to_string().lines().count())
+        };
+
+        assert_eq!(check(None, None), Ok(()));
+        assert_eq!(check(budget("6ms"), budget("21ms")), Ok(()));
+        assert_eq!(check(budget("4ms"), None), Err(2));
+        assert_eq!(check(None, budget("20ms")), Err(1));
+        assert_eq!(check(budget("1ms"), budget("1ms")), Err(6));
+    }
+}
@@ -0,0 +1,51 @@
+use anyhow::{Error, Result};
+use aoc_core::{generate::Generate, input, Report, Solution};
+use std::ops::RangeInclusive;
+
+pub const DAYS: RangeInclusive<u8> = 1..=15;
+
+pub fn run(day: u8) -> Result<Report> {
+    match day {
+        1 => solve::<day1::Day1>(),
+        2 => solve::<day2::Day2>(),
+        3 => solve::<day3::Day3>(),
+        4 => solve::<day4::Day4>(),
+        5 => solve::<day5::Day5>(),
+        6 => solve::<day6::Day6>(),
+        7 => solve::<day7::Day7>(),
+        8 => solve::<day8::Day8>(),
+        9 => solve::<day9::Day9>(),
+        10 => solve::<day10::Day10>(),
+        11 => solve::<day11::Day11>(),
+        12 => solve::<day12::Day12>(),
+        13 => solve::<day13::Day13>(),
+        14 => solve::<day14::Day14>(),
+        15 => solve::<day15::Day15>(),
+        _ => Err(Error::msg(format!("Day {day} has not been solved"))),
+    }
+}
+
+pub fn generate(day: u8, seed: u64, size: usize) -> Result<String> {
+    match day {
+        1 => Ok(day1::Day1::generate(seed, size)),
+        2 => Ok(day2::Day2::generate(seed, size)),
+        3 => Ok(day3::Day3::generate(seed, size)),
+        4 => Ok(day4::Day4::generate(seed, size)),
+        5 => Ok(day5::Day5::generate(seed, size)),
+        6 => Ok(day6::Day6::generate(seed, size)),
+        7 => Ok(day7::Day7::generate(seed, size)),
+        8 => Ok(day8::Day8::generate(seed, size)),
+        9 => Ok(day9::Day9::generate(seed, size)),
+        10 => Ok(day10::Day10::generate(seed, size)),
+        11 => Ok(day11::Day11::generate(seed, size)),
+        12 => Ok(day12::Day12::generate(seed, size)),
+        13 => Ok(day13::Day13::generate(seed, size)),
+        14 => Ok(day14::Day14::generate(seed, size)),
+        15 => Ok(day15::Day15::generate(seed, size)),
+        _ => Err(Error::msg(format!("Day {day} has not been solved"))),
+    }
+}
+
+fn solve<S: Solution>() -> Result<Report> {
+    aoc_core::run::<S>(input::load::<S>(None)?)
+}
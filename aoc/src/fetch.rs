@@ -0,0 +1,154 @@
+use anyhow::{Context, Error, Result};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u16 = 2022;
+const USER_AGENT: &str = "github.com/litpho/aoc-2022";
+
+/// Where to download inputs from and where to keep them.
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: String,
+    pub dir: PathBuf,
+}
+
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+impl Fetcher {
+    /// Downloads the input of `day` unless it's already on disk; inputs never change, so an
+    /// existing file is never fetched again.
+    pub fn fetch(&self, day: u8) -> Result<Fetched> {
+        let path = self.dir.join(format!("day{day}.txt"));
+        if path.is_file() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = format!(
+            "{}/{YEAR}/day/{day}/input",
+            self.base_url.trim_end_matches('/')
+        );
+        let input = ureq::get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()
+            .with_context(|| format!("Could not fetch the input for day {day} from {url}"))?
+            .into_string()?;
+        if input.is_empty() {
+            return Err(Error::msg(format!("{url} returned an empty input")));
+        }
+
+        write(&path, &input)?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+/// Writes through a temporary file so an interrupted download doesn't leave a truncated input
+/// behind that would then be mistaken for a cached one.
+fn write(path: &Path, input: &str) -> Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("Could not create {}", dir.display()))?;
+    }
+    let partial = path.with_extension("txt.partial");
+    fs::write(&partial, input).with_context(|| format!("Could not write {}", partial.display()))?;
+    fs::rename(&partial, path).with_context(|| format!("Could not write {}", path.display()))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        env,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    /// Serves `body` to a single request and returns the request line and headers it received.
+    fn serve(
+        status: &'static str,
+        body: &'static str,
+    ) -> Result<(String, JoinHandle<Vec<String>>)> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let base_url = format!("http://{}", listener.local_addr()?);
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let request = BufReader::new(&mut stream)
+                .lines()
+                .map(|line| line.unwrap())
+                .take_while(|line| !line.is_empty())
+                .collect::<Vec<String>>();
+            write!(
+                stream,
+                "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            request
+        });
+
+        Ok((base_url, handle))
+    }
+
+    fn fetcher(base_url: String, name: &str) -> Fetcher {
+        let dir = env::temp_dir().join(format!("aoc-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Fetcher {
+            base_url,
+            session: "53cr3t".to_owned(),
+            dir,
+        }
+    }
+
+    #[test]
+    fn test_fetch() -> Result<()> {
+        let (base_url, server) = serve("200 OK", "1000\n2000\n")?;
+        let fetcher = fetcher(base_url, "download");
+
+        let Fetched::Downloaded(path) = fetcher.fetch(1)? else {
+            panic!("expected the input to be downloaded");
+        };
+        assert_eq!(fs::read_to_string(path)?, "1000\n2000\n");
+
+        let request = server.join().unwrap();
+        assert_eq!(request[0], "GET /2022/day/1/input HTTP/1.1");
+        assert!(request
+            .iter()
+            .any(|h| h.eq_ignore_ascii_case("cookie: session=53cr3t")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_cached() -> Result<()> {
+        // Nothing listens here, so any request would fail.
+        let fetcher = fetcher("http://127.0.0.1:1".to_owned(), "cached");
+        fs::create_dir_all(&fetcher.dir)?;
+        fs::write(fetcher.dir.join("day2.txt"), "A Y\n")?;
+
+        assert!(matches!(fetcher.fetch(2)?, Fetched::Cached(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_error() -> Result<()> {
+        let (base_url, server) = serve("404 Not Found", "Not yet!")?;
+        let fetcher = fetcher(base_url, "error");
+
+        assert!(fetcher.fetch(25).is_err());
+        assert!(!fetcher.dir.join("day25.txt").exists());
+        server.join().unwrap();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,275 @@
+use anyhow::{Error, Result};
+use aoc_core::{
+    answers::{Answers, Verdict},
+    input,
+    output::{self, Format},
+    Report,
+};
+use clap::{Parser, Subcommand};
+use rayon::{iter::Either, prelude::*, ThreadPoolBuilder};
+use std::{
+    io,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+};
+
+mod budget;
+mod days;
+mod fetch;
+mod report;
+mod scaffold;
+mod selection;
+
+use budget::Budgets;
+use fetch::{Fetched, Fetcher};
+use report::{Failed, Totals};
+use selection::Selection;
+
+/// How many of the slowest phases to list after the timings.
+const SLOWEST: usize = 5;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2022 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the selected days and print their answers and timings
+    Run {
+        /// A single day (`7`), a range (`1..=15`, `1..15`) or `all`
+        #[arg(default_value = "all")]
+        days: Selection,
+
+        /// How to print the answers and timings
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+
+        /// The known answers to check against. Defaults to `inputs/answers.toml`
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// How many days and parts to run at once. Defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<NonZeroUsize>,
+
+        #[command(flatten)]
+        budgets: Budgets,
+    },
+    /// Run the selected days and fail if any answer differs from the known answers
+    Verify {
+        /// A single day (`7`), a range (`1..=15`, `1..15`) or `all`
+        #[arg(default_value = "all")]
+        days: Selection,
+
+        /// The known answers to check against. Defaults to `inputs/answers.toml`
+        #[arg(long)]
+        answers: Option<PathBuf>,
+
+        /// How many days and parts to run at once. Defaults to the number of CPUs
+        #[arg(long, short)]
+        jobs: Option<NonZeroUsize>,
+
+        #[command(flatten)]
+        budgets: Budgets,
+    },
+    /// Download the puzzle inputs of the selected days, skipping the ones already downloaded
+    Fetch {
+        /// A single day (`7`), a range (`1..=15`, `1..15`) or `all`
+        days: Selection,
+
+        /// The `session` cookie of a logged in Advent of Code account
+        #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+        session: String,
+
+        /// Where to download from, e.g. a local stand-in server
+        #[arg(long, default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+
+        /// Where to keep the inputs
+        #[arg(long, default_value = input::INPUT_DIR)]
+        dir: PathBuf,
+    },
+    /// Make up a valid input for a day and print it, to load test the solution with
+    Generate {
+        /// The day of the puzzle
+        day: u8,
+
+        /// The same seed always makes the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How big to make the input; what it counts depends on the day
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+    },
+    /// Generate the crate for a new day and add it to the workspace and the runner; run from the
+    /// workspace root
+    NewDay {
+        /// The day of the puzzle
+        day: u8,
+    },
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run {
+            days,
+            format,
+            answers,
+            jobs,
+            budgets,
+        } => run(&days, jobs, format, &load_answers(answers)?, &budgets),
+        Command::Verify {
+            days,
+            answers,
+            jobs,
+            budgets,
+        } => verify(&days, jobs, &load_answers(answers)?, &budgets),
+        Command::Fetch {
+            days,
+            session,
+            base_url,
+            dir,
+        } => fetch(
+            &days,
+            &Fetcher {
+                base_url,
+                session,
+                dir,
+            },
+        ),
+        Command::Generate { day, seed, size } => {
+            print!("{}", days::generate(day, seed, size)?);
+            Ok(())
+        }
+        Command::NewDay { day } => new_day(day),
+    }
+}
+
+fn run(
+    selection: &Selection,
+    jobs: Option<NonZeroUsize>,
+    format: Format,
+    answers: &Answers,
+    budgets: &Budgets,
+) -> Result<()> {
+    let (reports, failed, totals) = run_days(selection, jobs)?;
+
+    match format {
+        Format::Text => {
+            report::print_table(&reports, &failed, answers);
+            report::print_totals(&reports, &totals);
+            report::print_slowest(&reports, SLOWEST);
+        }
+        Format::Json => output::write_json(&reports, answers, io::stdout().lock())?,
+        Format::Csv => output::write_csv(&reports, answers, io::stdout().lock())?,
+    }
+
+    check_failed(&failed)?;
+    budgets.check(&reports)
+}
+
+fn verify(
+    selection: &Selection,
+    jobs: Option<NonZeroUsize>,
+    answers: &Answers,
+    budgets: &Budgets,
+) -> Result<()> {
+    let (reports, failed, totals) = run_days(selection, jobs)?;
+    report::print_table(&reports, &failed, answers);
+    report::print_totals(&reports, &totals);
+    report::print_slowest(&reports, SLOWEST);
+
+    let verdicts = reports
+        .iter()
+        .flat_map(|report| report.records(answers))
+        .map(|record| record.status)
+        .collect::<Vec<Verdict>>();
+    let count = |verdict| verdicts.iter().filter(|v| **v == verdict).count();
+    let incorrect = count(Verdict::Incorrect);
+
+    println!();
+    println!(
+        "{} correct, {incorrect} incorrect, {} unknown",
+        count(Verdict::Correct),
+        count(Verdict::Unknown)
+    );
+
+    check_failed(&failed)?;
+    if incorrect > 0 {
+        return Err(Error::msg(format!("Found {incorrect} incorrect answer(s)")));
+    }
+
+    budgets.check(&reports)
+}
+
+fn fetch(selection: &Selection, fetcher: &Fetcher) -> Result<()> {
+    for day in selection.days() {
+        match fetcher.fetch(day)? {
+            Fetched::Downloaded(path) => println!("Day {day}: downloaded to {}", path.display()),
+            Fetched::Cached(path) => println!("Day {day}: already at {}", path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn new_day(day: u8) -> Result<()> {
+    let dir = scaffold::new_day(Path::new("."), day)?;
+    println!("Created {}", dir.display());
+    println!("Registered Day{day} in aoc/Cargo.toml, aoc/src/days.rs and aoc/benches/days.rs");
+
+    Ok(())
+}
+
+/// Runs the selected days on a pool of `jobs` threads. A day that fails doesn't stop the others;
+/// the reports and failures are each in the order of the days, whichever finished first.
+fn run_days(
+    selection: &Selection,
+    jobs: Option<NonZeroUsize>,
+) -> Result<(Vec<Report>, Vec<Failed>, Totals)> {
+    let pool = ThreadPoolBuilder::new()
+        .num_threads(jobs.map_or(0, NonZeroUsize::get))
+        .build()?;
+    let (wall, (reports, failed)) = took::took(|| {
+        pool.install(|| {
+            selection
+                .days()
+                .into_par_iter()
+                .partition_map(|day| match days::run(day) {
+                    Ok(report) => Either::Left(report),
+                    Err(error) => Either::Right(Failed { day, error }),
+                })
+        })
+    });
+    let totals = Totals {
+        wall,
+        threads: pool.current_num_threads(),
+    };
+
+    Ok((reports, failed, totals))
+}
+
+/// Fails naming the days that failed, if any, once everything else has been printed.
+fn check_failed(failed: &[Failed]) -> Result<()> {
+    if failed.is_empty() {
+        return Ok(());
+    }
+    let days = failed
+        .iter()
+        .map(|failed| failed.day.to_string())
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    Err(Error::msg(format!("Day(s) {days} failed")))
+}
+
+fn load_answers(path: Option<PathBuf>) -> Result<Answers> {
+    match path {
+        Some(path) => Answers::load(&path),
+        None => Answers::load_default(),
+    }
+}
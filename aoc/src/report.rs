@@ -0,0 +1,172 @@
+use anyhow::Error;
+use aoc_core::{
+    answers::{Answers, Verdict},
+    Part, Report,
+};
+use std::time::Duration;
+use took::Took;
+
+use crate::budget;
+
+const HEADER: [&str; 8] = [
+    "Day", "Part one", "Part two", "Parsing", "Part one", "Part two", "Wall", "CPU",
+];
+
+/// A day that has no report, because loading its input, parsing or one of its parts failed.
+pub struct Failed {
+    pub day: u8,
+    pub error: Error,
+}
+
+/// Prints one row per day, marking answers that match the known answers with ✓ and those that
+/// don't with ✗. Answers spanning multiple lines (day 10's screen) don't fit in a cell, so they are
+/// printed underneath the table instead, as are the expected values of incorrect answers and why
+/// the failed days failed.
+pub fn print_table(reports: &[Report], failed: &[Failed], answers: &Answers) {
+    let mut rows = reports
+        .iter()
+        .map(|report| (report.day, row(report, answers)))
+        .chain(failed.iter().map(|failed| (failed.day, failed_row(failed))))
+        .collect::<Vec<(u8, [String; 8])>>();
+    rows.sort_by_key(|(day, _)| *day);
+    let rows = rows
+        .into_iter()
+        .map(|(_, row)| row)
+        .collect::<Vec<[String; 8]>>();
+
+    let mut widths = HEADER.map(str::len);
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row.iter()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    print_row(&HEADER.map(str::to_owned), &widths);
+    println!(
+        "{}",
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<String>>()
+            .join("-+-")
+    );
+    rows.iter().for_each(|row| print_row(row, &widths));
+
+    for report in reports.iter() {
+        for (number, name, part) in [(1, "one", &report.part_one), (2, "two", &report.part_two)] {
+            if part.answer.contains('\n') {
+                println!();
+                println!("Day {} part {name}:", report.day);
+                print!("{}", part.answer);
+            }
+            if answers.check(report.day, number, &part.answer) == Verdict::Incorrect {
+                let expected = answers.expected(report.day, number).unwrap_or_default();
+                println!();
+                if expected.contains('\n') {
+                    print!("Day {} part {name} should be:\n{expected}", report.day);
+                } else {
+                    println!("Day {} part {name} should be {expected}", report.day);
+                }
+            }
+        }
+    }
+    for failed in failed.iter() {
+        println!();
+        println!("Day {} failed: {:#}", failed.day, failed.error);
+    }
+}
+
+/// How running a selection of days went as a whole.
+pub struct Totals {
+    /// From starting the first day until the last one finished.
+    pub wall: Took,
+    /// The number of threads the days and their parts were spread over.
+    pub threads: usize,
+}
+
+/// Prints how long the days took from start to finish, next to the CPU time all threads spent on
+/// them together. The difference is what running them at the same time saved.
+pub fn print_totals(reports: &[Report], totals: &Totals) {
+    let cpu = reports
+        .iter()
+        .map(|report| report.cpu.as_ref().map(|cpu| *cpu.as_std()))
+        .sum::<Option<Duration>>()
+        .map(Took::from_std);
+
+    println!();
+    println!(
+        "{} days in {} wall-clock, {} CPU on {} threads",
+        reports.len(),
+        totals.wall,
+        cpu_cell(cpu.as_ref()),
+        totals.threads
+    );
+}
+
+/// Prints the `count` phases that took longest, with their share of the time all phases took
+/// together, so it's clear where making the calendar faster would pay off most.
+pub fn print_slowest(reports: &[Report], count: usize) {
+    let total = budget::total(reports).as_secs_f64();
+
+    println!();
+    println!("Slowest phases:");
+    for (rank, phase) in budget::phases(reports).iter().take(count).enumerate() {
+        let share = if total > 0.0 {
+            phase.took.as_secs_f64() / total * 100.0
+        } else {
+            0.0
+        };
+        println!("{:>3}. {phase} ({share:.0}%)", rank + 1);
+    }
+}
+
+fn row(report: &Report, answers: &Answers) -> [String; 8] {
+    [
+        report.day.to_string(),
+        cell(report.day, 1, &report.part_one, answers),
+        cell(report.day, 2, &report.part_two, answers),
+        report.parse.to_string(),
+        report.part_one.took.to_string(),
+        report.part_two.took.to_string(),
+        report.wall.to_string(),
+        cpu_cell(report.cpu.as_ref()),
+    ]
+}
+
+fn cpu_cell(cpu: Option<&Took>) -> String {
+    cpu.map_or_else(|| "n/a".to_owned(), |cpu| cpu.to_string())
+}
+
+fn failed_row(failed: &Failed) -> [String; 8] {
+    let mut row = [const { String::new() }; 8];
+    row[0] = failed.day.to_string();
+    row[1] = "failed".to_owned();
+    row[2] = "failed".to_owned();
+
+    row
+}
+
+fn cell(day: u8, number: u8, part: &Part, answers: &Answers) -> String {
+    let answer = if part.answer.contains('\n') {
+        "(see below)"
+    } else {
+        &part.answer
+    };
+
+    match answers.check(day, number, &part.answer) {
+        Verdict::Correct => format!("{answer} ✓"),
+        Verdict::Incorrect => format!("{answer} ✗"),
+        Verdict::Unknown => answer.to_owned(),
+    }
+}
+
+fn print_row(row: &[String; 8], widths: &[usize; 8]) {
+    println!(
+        "{}",
+        row.iter()
+            .zip(widths.iter())
+            .map(|(cell, width)| format!("{cell:>width$}"))
+            .collect::<Vec<String>>()
+            .join(" | ")
+    );
+}
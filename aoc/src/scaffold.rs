@@ -0,0 +1,404 @@
+use anyhow::{Context, Error, Result};
+use std::{
+    fs,
+    ops::RangeInclusive,
+    path::{Path, PathBuf},
+};
+
+/// Every day a puzzle can be released on.
+const PUZZLE_DAYS: RangeInclusive<u8> = 1..=25;
+
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    (
+        "src/generate.rs",
+        include_str!("../templates/generate.rs.tmpl"),
+    ),
+    ("src/test.txt", ""),
+];
+
+/// Where the runner lists the days, as the line listing a day with `{{day}}` standing for the
+/// day. A new day's line goes among the others, in numerical order.
+const REGISTRATIONS: [(&str, &[&str]); 3] = [
+    (
+        "aoc/Cargo.toml",
+        &[
+            "day{{day}} = { path = \"../day{{day}}\" }",
+            "    \"day{{day}}/embed-input\",",
+        ],
+    ),
+    (
+        "aoc/src/days.rs",
+        &[
+            "        {{day}} => solve::<day{{day}}::Day{{day}}>(),",
+            "        {{day}} => Ok(day{{day}}::Day{{day}}::generate(seed, size)),",
+        ],
+    ),
+    (
+        "aoc/benches/days.rs",
+        &["    bench::<day{{day}}::Day{{day}}>,"],
+    ),
+];
+
+/// Creates the `dayN` crate in the workspace at `root`, adds it to the workspace members and
+/// registers it with the runner. Returns the directory of the new crate.
+pub fn new_day(root: &Path, day: u8) -> Result<PathBuf> {
+    if !PUZZLE_DAYS.contains(&day) {
+        return Err(Error::msg(format!(
+            "{day} is not a puzzle day, expected {}..={}",
+            PUZZLE_DAYS.start(),
+            PUZZLE_DAYS.end()
+        )));
+    }
+
+    let name = format!("day{day}");
+    let dir = root.join(&name);
+    if dir.exists() {
+        return Err(Error::msg(format!("{} already exists", dir.display())));
+    }
+
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .with_context(|| format!("Could not read {}", manifest_path.display()))?;
+    let manifest = add_member(&manifest, &name)
+        .with_context(|| format!("Could not add {name} to {}", manifest_path.display()))?;
+
+    let mut registered = vec![(manifest_path, manifest)];
+    for (file, templates) in REGISTRATIONS {
+        let path = root.join(file);
+        let mut text = fs::read_to_string(&path)
+            .with_context(|| format!("Could not read {}", path.display()))?;
+        for template in templates {
+            text = register(&text, template, day)
+                .with_context(|| format!("Could not register {name} in {}", path.display()))?;
+        }
+        if file == "aoc/src/days.rs" {
+            text = extend_days(&text, day)
+                .with_context(|| format!("Could not register {name} in {}", path.display()))?;
+        }
+        registered.push((path, text));
+    }
+
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Could not create {}", parent.display()))?;
+        }
+        fs::write(&path, template.replace("{{day}}", &day.to_string()))
+            .with_context(|| format!("Could not write {}", path.display()))?;
+    }
+    for (path, text) in registered {
+        fs::write(&path, text).with_context(|| format!("Could not write {}", path.display()))?;
+    }
+
+    Ok(dir)
+}
+
+/// Adds `member` to the `members` list of a workspace manifest, keeping the days in numerical
+/// order after the other crates.
+fn add_member(manifest: &str, member: &str) -> Result<String> {
+    const MEMBERS: &str = "members = [";
+
+    let start = manifest
+        .find(MEMBERS)
+        .ok_or_else(|| Error::msg("There is no workspace members list"))?
+        + MEMBERS.len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or_else(|| Error::msg("The workspace members list is not closed"))?;
+
+    let mut members = manifest[start..end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"'))
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<&str>>();
+    if members.contains(&member) {
+        return Err(Error::msg(format!("{member} is already a member")));
+    }
+    members.push(member);
+    members.sort_by_key(|member| {
+        member
+            .strip_prefix("day")
+            .and_then(|day| day.parse::<u8>().ok())
+            .unwrap_or(0)
+    });
+
+    let members = members
+        .iter()
+        .map(|member| format!("    \"{member}\",\n"))
+        .collect::<String>();
+
+    Ok(format!(
+        "{}\n{members}{}",
+        &manifest[..start],
+        &manifest[end..]
+    ))
+}
+
+/// Adds the line listing `day` to the lines of `text` listing the other days, keeping them in
+/// numerical order. `template` is such a line, with `{{day}}` standing for the day.
+fn register(text: &str, template: &str, day: u8) -> Result<String> {
+    let line_for = |day: u8| template.replace("{{day}}", &day.to_string());
+    let prefix = &template[..template.find("{{day}}").unwrap_or_default()];
+    let listed = |line: &str| {
+        let rest = line.strip_prefix(prefix)?;
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let day = rest[..end].parse::<u8>().ok()?;
+        (line_for(day) == line).then_some(day)
+    };
+
+    let line = line_for(day);
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    let days = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| Some((index, listed(line)?)))
+        .collect::<Vec<(usize, u8)>>();
+    let Some(&(last, _)) = days.last() else {
+        return Err(Error::msg(format!(
+            "There is no day listed like `{}`",
+            line.trim()
+        )));
+    };
+    if days.iter().any(|(_, listed)| *listed == day) {
+        return Err(Error::msg(format!("Day {day} is already listed")));
+    }
+    let index = days
+        .iter()
+        .find(|(_, listed)| *listed > day)
+        .map_or(last + 1, |(index, _)| *index);
+    lines.insert(index, &line);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Raises the last of the runner's `DAYS` to `day` if it's later, so that running all days
+/// includes it. Days in between that have no crate yet fail when run.
+fn extend_days(source: &str, day: u8) -> Result<String> {
+    const DAYS: &str = "pub const DAYS: RangeInclusive<u8> = 1..=";
+
+    let start = source
+        .find(DAYS)
+        .ok_or_else(|| Error::msg("There is no DAYS range"))?
+        + DAYS.len();
+    let end = start
+        + source[start..]
+            .find(';')
+            .ok_or_else(|| Error::msg("The DAYS range is not closed"))?;
+    let last = source[start..end]
+        .parse::<u8>()
+        .with_context(|| format!("{} is not the last day", &source[start..end]))?;
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..start],
+        last.max(day),
+        &source[end..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, process::Command};
+
+    const CARGO: &str = include_str!("../Cargo.toml");
+    const DAYS: &str = include_str!("days.rs");
+    const BENCHES: &str = include_str!("../benches/days.rs");
+
+    const MANIFEST: &str = r#"[workspace]
+resolver = "2"
+
+members = [
+    "aoc",
+    "aoc-core",
+    "day1",
+    "day2",
+    "day10",
+]
+"#;
+
+    /// A workspace with `manifest` and the runner's lists of days, but none of the crates.
+    fn workspace(name: &str, manifest: &str) -> Result<PathBuf> {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src"))?;
+        fs::create_dir_all(root.join("aoc/benches"))?;
+        fs::write(root.join("Cargo.toml"), manifest)?;
+        fs::write(root.join("aoc/Cargo.toml"), CARGO)?;
+        fs::write(root.join("aoc/src/days.rs"), DAYS)?;
+        fs::write(root.join("aoc/benches/days.rs"), BENCHES)?;
+
+        Ok(root)
+    }
+
+    fn copy_dir(from: &Path, to: &Path) -> Result<()> {
+        fs::create_dir_all(to)?;
+        for entry in fs::read_dir(from)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                copy_dir(&entry.path(), &to.join(entry.file_name()))?;
+            } else {
+                fs::copy(entry.path(), to.join(entry.file_name()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_member() -> Result<()> {
+        assert_eq!(
+            add_member(MANIFEST, "day3")?,
+            r#"[workspace]
+resolver = "2"
+
+members = [
+    "aoc",
+    "aoc-core",
+    "day1",
+    "day2",
+    "day3",
+    "day10",
+]
+"#
+        );
+        assert!(add_member(MANIFEST, "day2").is_err());
+        assert!(add_member("[workspace]\n", "day2").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_register() -> Result<()> {
+        let template = "        {{day}} => solve::<day{{day}}::Day{{day}}>(),";
+        let text = "match day {\n        1 => solve::<day1::Day1>(),\n        \
+                    10 => solve::<day10::Day10>(),\n        _ => unreachable!(),\n}\n";
+
+        assert_eq!(
+            register(text, template, 2)?,
+            "match day {\n        1 => solve::<day1::Day1>(),\n        \
+             2 => solve::<day2::Day2>(),\n        10 => solve::<day10::Day10>(),\n        \
+             _ => unreachable!(),\n}\n"
+        );
+        assert!(register(&register(text, template, 11)?, template, 11).is_err());
+        assert!(register(text, template, 1).is_err());
+        assert!(register("match day {}\n", template, 2).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_extend_days() -> Result<()> {
+        let source = "pub const DAYS: RangeInclusive<u8> = 1..=15;\n";
+        assert_eq!(
+            extend_days(source, 16)?,
+            "pub const DAYS: RangeInclusive<u8> = 1..=16;\n"
+        );
+        assert_eq!(extend_days(source, 3)?, source);
+        assert!(extend_days("pub const DAYS: &[u8] = &[1];\n", 16).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day() -> Result<()> {
+        let root = workspace("new", MANIFEST)?;
+        let dir = new_day(&root, 16)?;
+
+        assert!(fs::read_to_string(root.join("Cargo.toml"))?.contains("    \"day16\",\n]"));
+        let cargo = fs::read_to_string(root.join("aoc/Cargo.toml"))?;
+        assert!(
+            cargo.contains("day15 = { path = \"../day15\" }\nday16 = { path = \"../day16\" }\n")
+        );
+        assert!(cargo.contains("    \"day15/embed-input\",\n    \"day16/embed-input\",\n"));
+        let days = fs::read_to_string(root.join("aoc/src/days.rs"))?;
+        assert!(days.contains("pub const DAYS: RangeInclusive<u8> = 1..=16;"));
+        assert!(days.contains("        16 => solve::<day16::Day16>(),\n        _ =>"));
+        assert!(
+            days.contains("        16 => Ok(day16::Day16::generate(seed, size)),\n        _ =>")
+        );
+        let benches = fs::read_to_string(root.join("aoc/benches/days.rs"))?;
+        assert!(benches.contains("    bench::<day16::Day16>,\n);"));
+        assert!(fs::read_to_string(dir.join("Cargo.toml"))?.contains("name = \"day16\""));
+        assert!(fs::read_to_string(dir.join("src/main.rs"))?.contains("aoc_core::main::<Day16>()"));
+        let lib = fs::read_to_string(dir.join("src/lib.rs"))?;
+        assert!(lib.contains("impl Solution for Day16 {"));
+        assert!(lib.contains("const DAY: u8 = 16;"));
+        assert!(!lib.contains("{{day}}"));
+        let generate = fs::read_to_string(dir.join("src/generate.rs"))?;
+        assert!(generate.contains("impl Generate for Day16 {"));
+        assert!(dir.join("src/test.txt").is_file());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day_existing() -> Result<()> {
+        let root = workspace("existing", MANIFEST)?;
+        fs::create_dir(root.join("day2"))?;
+
+        assert!(new_day(&root, 2).is_err());
+        assert!(new_day(&root, 26).is_err());
+        // Already listed by the runner, though there is no crate.
+        assert!(new_day(&root, 3).is_err());
+        assert!(!root.join("day3").exists());
+        assert_eq!(fs::read_to_string(root.join("Cargo.toml"))?, MANIFEST);
+        assert_eq!(fs::read_to_string(root.join("aoc/src/days.rs"))?, DAYS);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_day_builds() -> Result<()> {
+        // This workspace's own manifest, so the new day builds with the same settings, but with
+        // only the crate every day needs as a member.
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+        let manifest = fs::read_to_string(source.join("Cargo.toml"))?;
+        let start = manifest.find("members = [").unwrap_or_default();
+        let end = start + manifest[start..].find(']').unwrap_or_default() + 1;
+        let manifest = format!(
+            "{}members = [\"aoc-core\"]{}",
+            &manifest[..start],
+            &manifest[end..]
+        );
+
+        let root = workspace("builds", &manifest)?;
+        copy_dir(&source.join("aoc-core/src"), &root.join("aoc-core/src"))?;
+        fs::copy(
+            source.join("aoc-core/Cargo.toml"),
+            root.join("aoc-core/Cargo.toml"),
+        )?;
+        if source.join("Cargo.lock").is_file() {
+            fs::copy(source.join("Cargo.lock"), root.join("Cargo.lock"))?;
+        }
+        new_day(&root, 16)?;
+
+        // Building the runner's dependencies already downloaded everything the day needs. The
+        // target directory outlives the test, so later runs only build the new day.
+        let status = Command::new(env!("CARGO"))
+            .args([
+                "check",
+                "--offline",
+                "--quiet",
+                "--all-targets",
+                "-p",
+                "day16",
+            ])
+            .current_dir(&root)
+            .env(
+                "CARGO_TARGET_DIR",
+                env::temp_dir().join("aoc-scaffold-target"),
+            )
+            .status()?;
+        assert!(status.success());
+
+        Ok(())
+    }
+}
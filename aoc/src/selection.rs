@@ -0,0 +1,92 @@
+use anyhow::{Error, Result};
+use std::{ops::RangeInclusive, str::FromStr};
+
+use crate::days::DAYS;
+
+/// A contiguous range of days picked on the command line.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Selection(RangeInclusive<u8>);
+
+impl Selection {
+    pub fn days(&self) -> RangeInclusive<u8> {
+        self.0.clone()
+    }
+}
+
+impl FromStr for Selection {
+    type Err = Error;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let range = if s == "all" {
+            DAYS
+        } else if let Some((start, end)) = s.split_once("..=") {
+            parse_day(start)?..=parse_day(end)?
+        } else if let Some((start, end)) = s.split_once("..") {
+            parse_day(start)?..=parse_day(end)?.saturating_sub(1)
+        } else {
+            let day = parse_day(s)?;
+            day..=day
+        };
+
+        if range.is_empty() {
+            return Err(Error::msg(format!("{s} does not select any days")));
+        }
+        if !DAYS.contains(range.start()) || !DAYS.contains(range.end()) {
+            return Err(Error::msg(format!(
+                "{s} is outside of the solved days {}..={}",
+                DAYS.start(),
+                DAYS.end()
+            )));
+        }
+
+        Ok(Selection(range))
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8> {
+    s.trim()
+        .parse::<u8>()
+        .map_err(|_| Error::msg(format!("{s} is not a valid day")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_single_day() -> Result<()> {
+        assert_eq!(Selection::from_str("7")?.days(), 7..=7);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_inclusive_range() -> Result<()> {
+        assert_eq!(Selection::from_str("1..=15")?.days(), 1..=15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exclusive_range() -> Result<()> {
+        assert_eq!(Selection::from_str("3..7")?.days(), 3..=6);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_all() -> Result<()> {
+        assert_eq!(Selection::from_str("all")?.days(), DAYS);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert!(Selection::from_str("0").is_err());
+        assert!(Selection::from_str("16").is_err());
+        assert!(Selection::from_str("7..=3").is_err());
+        assert!(Selection::from_str("3..3").is_err());
+        assert!(Selection::from_str("seven").is_err());
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day1;
+
+impl Generate for Day1 {
+    /// `size` elves, at least the three part two needs, each carrying a few snacks.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size.max(3))
+            .map(|_| {
+                (0..rng.range(1..=10))
+                    .map(|_| format!("{}\n", rng.range(1000..=60_000)))
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day1>(Day1::generate(seed, 50).leak())?;
+        }
+
+        Ok(())
+    }
+}
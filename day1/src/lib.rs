@@ -0,0 +1,165 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{paragraphs, parse_all, unsigned_list, IResult},
+    Solution,
+};
+use nom::{
+    character::complete::line_ending,
+    combinator::{cut, map_res, success},
+    error::context,
+    Parser,
+};
+
+pub struct Day1;
+
+/// Why the calorie counts don't give a top elf or a top three.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("There are no elves")]
+    NoElves,
+    #[error("There are only {0} elves where the top three are needed")]
+    TooFewElves(usize),
+    #[error("The top three elves carry more calories than can be added up")]
+    CaloriesOverflow,
+}
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day1.txt"));
+
+    type Input = Vec<u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[u32]) -> Result<u32, Error> {
+    input.iter().max().copied().ok_or(Error::NoElves)
+}
+
+pub fn part_two(input: &[u32]) -> Result<u32, Error> {
+    if input.len() < 3 {
+        return Err(Error::TooFewElves(input.len()));
+    }
+
+    let mut input = input.to_vec();
+    input.sort_by(|a, b| b.cmp(a));
+    input[..3]
+        .iter()
+        .try_fold(0u32, |sum, calories| sum.checked_add(*calories))
+        .ok_or(Error::CaloriesOverflow)
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<u32>> {
+    paragraphs(parse_lines).parse(input)
+}
+
+fn parse_lines(input: &str) -> IResult<&str, u32> {
+    let (rest, items) =
+        context("an elf's calories", unsigned_list::<u32, _, _>(line_ending)).parse(input)?;
+    // Calories that don't add up fail the input there, rather than ending the list of elves
+    // before them.
+    let (_, total) = context(
+        "an elf's total calories",
+        cut(map_res(success(items), |items| {
+            items
+                .iter()
+                .try_fold(0u32, |sum, item| sum.checked_add(*item))
+                .ok_or_else(|| format!("The calories add up to more than {}", u32::MAX))
+        })),
+    )
+    .parse(input)?;
+
+    Ok((rest, total))
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<u32>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+    const TESTDATA_OVERFLOW: &str = include_str!("test_overflow.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(24000, part_one(&parse(TESTDATA)?.1)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day1>(None)?)?;
+        assert_eq!(72511, part_one(&input)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse(TESTDATA)?.1)?, 45000);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 24000);
+            assert_eq!(part_two(&input)?, 45000);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day1>(None)?)?;
+        assert_eq!(part_two(&input)?, 212117);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_too_few_elves() {
+        assert!(matches!(part_one(&[]), Err(Error::NoElves)));
+        assert!(matches!(part_two(&[1, 2]), Err(Error::TooFewElves(2))));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let error = parse_input(TESTDATA_OVERFLOW).unwrap_err().to_string();
+        println!("{error}");
+        assert!(error.contains("an elf's total calories"));
+        let error = parse_input("1\n\n4000000000\n4000000000\n")
+            .unwrap_err()
+            .to_string();
+        println!("{error}");
+
+        let top = [u32::MAX, 1, 1];
+        assert!(matches!(part_two(&top), Err(Error::CaloriesOverflow)));
+    }
+}
@@ -0,0 +1,58 @@
+//! Property tests checking the solutions against brute-force versions on random inventories.
+
+use super::*;
+use proptest::prelude::*;
+
+fn elves() -> impl Strategy<Value = Vec<Vec<u32>>> {
+    prop::collection::vec(prop::collection::vec(0..100_000u32, 1..6), 3..20)
+}
+
+fn render(elves: &[Vec<u32>]) -> &'static str {
+    elves
+        .iter()
+        .map(|items| {
+            items
+                .iter()
+                .map(|item| format!("{item}\n"))
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+        .leak()
+}
+
+/// The calories carried by the `n` elves carrying the most, taking them out one at a time.
+fn top(elves: &[Vec<u32>], n: usize) -> u32 {
+    let mut totals = elves
+        .iter()
+        .map(|items| items.iter().sum::<u32>())
+        .collect::<Vec<u32>>();
+    let mut sum = 0;
+    for _ in 0..n {
+        let (i, max) = totals.iter().enumerate().fold((0, 0), |best, (i, total)| {
+            if *total > best.1 {
+                (i, *total)
+            } else {
+                best
+            }
+        });
+        sum += max;
+        totals.remove(i);
+    }
+
+    sum
+}
+
+proptest! {
+    #[test]
+    fn part_one_is_the_largest_total(elves in elves()) {
+        let input = parse_input(render(&elves)).unwrap();
+        prop_assert_eq!(part_one(&input)?, top(&elves, 1));
+    }
+
+    #[test]
+    fn part_two_is_the_largest_three_totals(elves in elves()) {
+        let input = parse_input(render(&elves)).unwrap();
+        prop_assert_eq!(part_two(&input)?, top(&elves, 3));
+    }
+}
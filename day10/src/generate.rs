@@ -0,0 +1,44 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::{Day10, HEIGHT, WIDTH};
+
+impl Generate for Day10 {
+    /// A program of `size` instructions, or as many as it takes to draw the whole screen. The
+    /// sprite stays on the screen.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let (mut program, mut cycles, mut x) = (String::new(), 0, 1);
+        for _ in 0..size {
+            if rng.chance(0.5) {
+                program += "noop\n";
+                cycles += 1;
+            } else {
+                let to = rng.range(0..=WIDTH as i32 - 1);
+                program += &format!("addx {}\n", to - x);
+                (cycles, x) = (cycles + 2, to);
+            }
+        }
+        while cycles < WIDTH * HEIGHT {
+            program += "noop\n";
+            cycles += 1;
+        }
+
+        program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day10>(Day10::generate(seed, 150).leak())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,244 @@
+use anyhow::Result;
+use aoc_core::{
+    grid::Grid,
+    parse::{lines, parse_all, IResult},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::{map, value},
+    error::context,
+    sequence::preceded,
+    Parser,
+};
+use std::collections::HashMap;
+
+pub struct Day10;
+
+/// Why the program gives no signal strengths or picture.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The program stops after {0} cycles, before the register is read during cycle {1}")]
+    ProgramTooShort(usize, usize),
+    #[error("Cycle {0} makes a value too large to keep track of")]
+    Overflow(usize),
+}
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day10.txt"));
+
+    type Input = Vec<Instruction>;
+    type PartOne = i32;
+    type PartTwo = String;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(visualize(&part_two(input)?))
+    }
+}
+
+pub fn part_one(input: &[Instruction]) -> Result<i32, Error> {
+    let state = calculate_state(input)?;
+
+    [20, 60, 100, 140, 180, 220]
+        .into_iter()
+        .try_fold(0i32, |sum, cycle| {
+            let x = state
+                .get(&(cycle - 1))
+                .ok_or(Error::ProgramTooShort(input.len(), cycle))?;
+            (cycle as i32)
+                .checked_mul(*x)
+                .and_then(|strength| sum.checked_add(strength))
+                .ok_or(Error::Overflow(cycle))
+        })
+}
+
+pub fn part_two(input: &[Instruction]) -> Result<Grid<bool>, Error> {
+    let state = calculate_state(input)?;
+    let mut sprite: i32 = 1;
+    let mut screen = Grid::new(WIDTH, HEIGHT, false);
+
+    for cycle in 1..=WIDTH * HEIGHT {
+        if let Some(new_sprite_x) = state.get(&(cycle - 1)) {
+            sprite = *new_sprite_x;
+        }
+        let pixel = ((cycle - 1) % WIDTH, (cycle - 1) / WIDTH);
+        if sprite.abs_diff(pixel.0 as i32) <= 1 {
+            screen[pixel] = true;
+        }
+    }
+
+    Ok(screen)
+}
+
+pub fn visualize(screen: &Grid<bool>) -> String {
+    screen.map(|lit| if *lit { '▓' } else { '░' }).to_string()
+}
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+fn calculate_state(input: &[Instruction]) -> Result<HashMap<usize, i32>, Error> {
+    let mut x: i32 = 1;
+    let mut state: HashMap<usize, i32> = HashMap::new();
+    for (cycle, instruction) in input.iter().enumerate() {
+        if let Instruction::AddX(amount) = instruction {
+            x = x.checked_add(*amount).ok_or(Error::Overflow(cycle + 1))?;
+        }
+        state.insert(cycle + 1, x);
+    }
+
+    Ok(state)
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Instruction {
+    AddX(i32),
+    Noop,
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Vec<Instruction>>> {
+    lines(parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<Instruction>> {
+    context("an instruction", alt((parse_addx, parse_noop))).parse(input)
+}
+
+fn parse_addx(input: &str) -> IResult<&str, Vec<Instruction>> {
+    map(preceded(tag("addx "), complete::i32), |amount| {
+        vec![Instruction::Noop, Instruction::AddX(amount)]
+    })
+    .parse(input)
+}
+
+fn parse_noop(input: &str) -> IResult<&str, Vec<Instruction>> {
+    value(vec![Instruction::Noop], tag("noop")).parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<Instruction>> {
+    let input = parse_all(parse, input)?
+        .into_iter()
+        .flat_map(|a| a.into_iter())
+        .collect::<Vec<Instruction>>();
+
+    Ok(input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    /// The lit pixels numbered as cycles, left to right and top to bottom starting at 1.
+    fn lit_pixels(screen: &Grid<bool>) -> Vec<usize> {
+        screen
+            .positions(|lit| *lit)
+            .map(|(x, y)| y * WIDTH + x + 1)
+            .collect::<Vec<usize>>()
+    }
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 13140);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        assert_eq!(part_one(&parse_input(load::<Day10>(None)?)?)?, 15260);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let expected = vec![
+            1, 2, 5, 6, 9, 10, 13, 14, 17, 18, 21, 22, 25, 26, 29, 30, 33, 34, 37, 38, 41, 42, 43,
+            47, 48, 49, 53, 54, 55, 59, 60, 61, 65, 66, 67, 71, 72, 73, 77, 78, 79, 81, 82, 83, 84,
+            89, 90, 91, 92, 97, 98, 99, 100, 105, 106, 107, 108, 113, 114, 115, 116, 121, 122, 123,
+            124, 125, 131, 132, 133, 134, 135, 141, 142, 143, 144, 145, 151, 152, 153, 154, 155,
+            161, 162, 163, 164, 165, 166, 173, 174, 175, 176, 177, 178, 185, 186, 187, 188, 189,
+            190, 197, 198, 199, 200, 201, 202, 203, 204, 205, 206, 207, 215, 216, 217, 218, 219,
+            220, 221, 229, 230, 231, 232, 233, 234, 235,
+        ];
+        assert_eq!(expected, lit_pixels(&part_two(&parse_input(TESTDATA)?)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 13140);
+            assert_eq!(
+                lit_pixels(&part_two(&input)?),
+                lit_pixels(&part_two(&parse_input(TESTDATA)?)?)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let expected = vec![
+            1, 2, 3, 7, 8, 11, 14, 16, 17, 18, 19, 22, 23, 26, 31, 34, 37, 38, 41, 44, 46, 49, 51,
+            54, 56, 61, 64, 66, 71, 74, 76, 79, 81, 84, 86, 91, 92, 93, 94, 96, 97, 98, 101, 106,
+            111, 114, 116, 121, 122, 123, 126, 128, 129, 131, 134, 136, 141, 143, 144, 146, 151,
+            154, 156, 158, 159, 161, 166, 169, 171, 174, 176, 181, 184, 186, 191, 194, 196, 199,
+            201, 207, 208, 209, 211, 214, 216, 222, 223, 224, 226, 227, 228, 229, 232, 233, 237,
+            238, 239,
+        ];
+        assert_eq!(
+            expected,
+            lit_pixels(&part_two(&parse_input(load::<Day10>(None)?)?)?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_program_too_short() -> Result<()> {
+        let input = parse_input("noop\naddx 3\naddx -5\n")?;
+        assert!(matches!(
+            part_one(&input),
+            Err(Error::ProgramTooShort(5, 20))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overflow() -> Result<()> {
+        let input = parse_input("addx 2147483647\n")?;
+        assert!(matches!(part_one(&input), Err(Error::Overflow(2))));
+        assert!(matches!(part_two(&input), Err(Error::Overflow(2))));
+
+        // The register fits, but its signal strength during cycle 20 doesn't.
+        let input = parse_input(format!("addx 200000000\n{}", "noop\n".repeat(20)).leak())?;
+        assert!(matches!(part_one(&input), Err(Error::Overflow(20))));
+
+        Ok(())
+    }
+}
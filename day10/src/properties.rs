@@ -0,0 +1,70 @@
+//! Property tests checking the signal strengths and screen against a cycle-by-cycle CPU on random
+//! programs.
+
+use super::*;
+use aoc_core::grid::Point;
+use proptest::prelude::*;
+
+/// Programs of `noop`s and `addx`s, some of them too short to reach cycle 220.
+fn programs() -> impl Strategy<Value = Vec<Option<i32>>> {
+    prop::collection::vec(prop::option::of(-20..=20i32), 100..200)
+}
+
+fn render(program: &[Option<i32>]) -> &'static str {
+    program
+        .iter()
+        .map(|instruction| match instruction {
+            Some(amount) => format!("addx {amount}\n"),
+            None => "noop\n".to_owned(),
+        })
+        .collect::<String>()
+        .leak()
+}
+
+/// The value of the register during every cycle the program runs for, and its final value.
+fn run(program: &[Option<i32>]) -> (Vec<i32>, i32) {
+    let mut x = 1;
+    let mut during = vec![];
+    for instruction in program {
+        match instruction {
+            Some(amount) => {
+                during.extend([x, x]);
+                x += amount;
+            }
+            None => during.push(x),
+        }
+    }
+
+    (during, x)
+}
+
+proptest! {
+    #[test]
+    fn part_one_sums_signal_strengths(program in programs()) {
+        let input = parse_input(render(&program)).unwrap();
+        let (during, last) = run(&program);
+        // The register keeps its final value for one more cycle after the program ends.
+        let expected = (during.len() + 1 >= 220).then(|| {
+            [20, 60, 100, 140, 180, 220]
+                .into_iter()
+                .map(|cycle| cycle as i32 * during.get(cycle - 1).copied().unwrap_or(last))
+                .sum::<i32>()
+        });
+        prop_assert_eq!(part_one(&input).ok(), expected);
+    }
+
+    #[test]
+    fn part_two_draws_where_the_sprite_is(program in programs()) {
+        let input = parse_input(render(&program)).unwrap();
+        let (during, last) = run(&program);
+        let expected = (0..WIDTH * HEIGHT)
+            .filter(|pixel| {
+                let sprite = during.get(*pixel).copied().unwrap_or(last);
+                (sprite - (pixel % WIDTH) as i32).abs() <= 1
+            })
+            .map(|pixel| (pixel % WIDTH, pixel / WIDTH))
+            .collect::<Vec<Point>>();
+        let screen = part_two(&input)?;
+        prop_assert_eq!(screen.positions(|lit| *lit).collect::<Vec<Point>>(), expected);
+    }
+}
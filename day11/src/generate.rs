@@ -0,0 +1,83 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day11;
+
+/// The divisors the monkeys test with. They're distinct primes, so coprime, and their product
+/// squared still fits in a worry level.
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+impl Generate for Day11 {
+    /// `size` monkeys, between three and nine. Like in the puzzle, one of them squares the worry
+    /// level and the others add or multiply a small number.
+    ///
+    /// Part one only divides worry levels by three, so they have to be kept from growing out of
+    /// a `u64` in other ways: nobody throws to the monkey that squares, and monkeys that multiply
+    /// by at most nine only throw to monkeys that add, which bring the level back down.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let count = size.clamp(3, DIVISORS.len());
+        let mut divisors = DIVISORS.to_vec();
+        rng.shuffle(&mut divisors);
+        let squaring = rng.range(0..=count - 1);
+        let others = (0..count)
+            .filter(|id| *id != squaring)
+            .collect::<Vec<usize>>();
+        let mut adding = others
+            .iter()
+            .map(|_| rng.chance(0.5))
+            .collect::<Vec<bool>>();
+        let forced = rng.range(0..=others.len() - 1);
+        adding[forced] = true;
+        let adders = others
+            .iter()
+            .zip(&adding)
+            .filter_map(|(id, adds)| adds.then_some(*id))
+            .collect::<Vec<usize>>();
+
+        (0..count)
+            .map(|id| {
+                let items = (0..rng.range(1..=6))
+                    .map(|_| rng.range(50..=99).to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ");
+                let (operation, targets) = if id == squaring {
+                    ("* old".to_owned(), &others)
+                } else if adders.contains(&id) {
+                    (format!("+ {}", rng.range(1..=8)), &others)
+                } else {
+                    (format!("* {}", rng.range(2..=9)), &adders)
+                };
+                let targets = targets
+                    .iter()
+                    .filter(|target| **target != id)
+                    .copied()
+                    .collect::<Vec<usize>>();
+                let (if_true, if_false) = (*rng.pick(&targets), *rng.pick(&targets));
+
+                format!(
+                    "Monkey {id}:\n  Starting items: {items}\n  Operation: new = old {operation}\n  \
+                     Test: divisible by {}\n    If true: throw to monkey {if_true}\n    If false: \
+                     throw to monkey {if_false}\n",
+                    divisors[id]
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..5 {
+            aoc_core::run::<Day11>(Day11::generate(seed, 8).leak())?;
+        }
+
+        Ok(())
+    }
+}
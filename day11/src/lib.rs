@@ -0,0 +1,362 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{paragraphs, parse_all, unsigned_list, IResult},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    combinator::{map, value},
+    error::context,
+    sequence::{delimited, preceded, terminated},
+    Parser,
+};
+use std::{collections::HashMap, ops::Rem};
+
+pub struct Day11;
+
+/// Why the monkeys can't be followed through the rounds.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Monkey {monkey} throws to monkey {target}, which doesn't exist")]
+    NoSuchMonkey { monkey: usize, target: usize },
+    #[error("Monkey {0} tests for divisibility by zero")]
+    DivisionByZero(usize),
+    #[error("Monkey {0} makes a worry level too large to keep track of")]
+    WorryOverflow(usize),
+    #[error("The monkeys' divisors multiply to more than a worry level can hold")]
+    DivisorsTooLarge,
+    #[error("Only {0} monkeys inspected any items where two are needed")]
+    TooFewMonkeys(usize),
+}
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day11.txt"));
+
+    type Input = Vec<Monkey>;
+    type PartOne = u64;
+    type PartTwo = u64;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[Monkey]) -> Result<u64, Error> {
+    do_it(&mut input.to_vec(), 20, |worry_level| worry_level / 3)
+}
+
+pub fn part_two(input: &[Monkey]) -> Result<u64, Error> {
+    let product = input
+        .iter()
+        .try_fold(1u64, |product, m| product.checked_mul(m.test_div))
+        .ok_or(Error::DivisorsTooLarge)?;
+    do_it(&mut input.to_vec(), 10_000, |x: u64| -> u64 { x % product })
+}
+
+/// Catches the inputs that would make the monkeys' game impossible to play out.
+fn check_monkeys(input: &[Monkey]) -> Result<(), Error> {
+    for monkey in input.iter() {
+        if monkey.test_div == 0 {
+            return Err(Error::DivisionByZero(monkey.id));
+        }
+        if let Some(target) = [monkey.target_true, monkey.target_false]
+            .into_iter()
+            .find(|target| *target >= input.len())
+        {
+            return Err(Error::NoSuchMonkey {
+                monkey: monkey.id,
+                target,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn do_it<F>(input: &mut [Monkey], num_rounds: usize, worry_modifier: F) -> Result<u64, Error>
+where
+    F: Fn(u64) -> u64,
+{
+    check_monkeys(input)?;
+
+    let amount_of_monkeys = input.len();
+    let mut inspections: HashMap<usize, usize> = HashMap::new();
+    for _ in 0..num_rounds {
+        for monkey_id in 0..amount_of_monkeys {
+            let monkey = &mut input[monkey_id];
+            let mut items_thrown: HashMap<usize, Vec<u64>> = HashMap::new();
+            for item in monkey.items.iter() {
+                *inspections.entry(monkey.id).or_default() += 1;
+                let worry_level = monkey
+                    .operation
+                    .apply(*item)
+                    .ok_or(Error::WorryOverflow(monkey.id))?;
+                let worry_level = worry_modifier(worry_level);
+                let target_monkey = if worry_level.rem(monkey.test_div) == 0 {
+                    monkey.target_true
+                } else {
+                    monkey.target_false
+                };
+                items_thrown
+                    .entry(target_monkey)
+                    .or_default()
+                    .push(worry_level);
+            }
+            monkey.items.clear();
+            for (id, items) in items_thrown {
+                input[id].items.extend(items);
+            }
+        }
+    }
+
+    let mut times = inspections.values().copied().collect::<Vec<usize>>();
+    times.sort_by(|a, b| b.cmp(a));
+
+    match times[..] {
+        [first, second, ..] => Ok(first as u64 * second as u64),
+        _ => Err(Error::TooFewMonkeys(times.len())),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    id: usize,
+    items: Vec<u64>,
+    operation: Operation,
+    test_div: u64,
+    target_true: usize,
+    target_false: usize,
+}
+
+impl Monkey {
+    pub fn new(
+        id: usize,
+        items: Vec<u64>,
+        operation: Operation,
+        test_div: u64,
+        target_true: usize,
+        target_false: usize,
+    ) -> Self {
+        Monkey {
+            id,
+            items,
+            operation,
+            test_div,
+            target_true,
+            target_false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum Operation {
+    Addition(u64),
+    Multiplication(u64),
+    Squared,
+}
+
+impl Operation {
+    /// The new worry level, or `None` if it doesn't fit.
+    pub fn apply(&self, operand: u64) -> Option<u64> {
+        match self {
+            Operation::Addition(x) => x.checked_add(operand),
+            Operation::Multiplication(x) => x.checked_mul(operand),
+            Operation::Squared => operand.checked_mul(operand),
+        }
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Monkey>> {
+    paragraphs(parse_monkey).parse(input)
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    context(
+        "a monkey",
+        map(
+            (
+                context("the monkey's number", parse_monkey_id_line),
+                context("the starting items", parse_monkey_items_line),
+                context("the operation", parse_operation_line),
+                context("the test", parse_test_div_line),
+                context("the target if true", parse_target_true_line),
+                context("the target if false", parse_target_false),
+            ),
+            |(id, items, operation, test_div, target_true, target_false)| {
+                Monkey::new(id, items, operation, test_div, target_true, target_false)
+            },
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_monkey_id_line(input: &str) -> IResult<&str, usize> {
+    map(
+        terminated(
+            delimited(tag("Monkey "), complete::u8, complete::char(':')),
+            line_ending,
+        ),
+        |id| id as usize,
+    )
+    .parse(input)
+}
+
+fn parse_monkey_items_line(input: &str) -> IResult<&str, Vec<u64>> {
+    terminated(
+        preceded(tag("  Starting items: "), unsigned_list(tag(", "))),
+        line_ending,
+    )
+    .parse(input)
+}
+
+fn parse_operation_line(input: &str) -> IResult<&str, Operation> {
+    terminated(
+        preceded(
+            tag("  Operation: new = old "),
+            alt((
+                parse_self_multiplication,
+                parse_addition,
+                parse_multiplication,
+            )),
+        ),
+        line_ending,
+    )
+    .parse(input)
+}
+
+fn parse_addition(input: &str) -> IResult<&str, Operation> {
+    map(preceded(tag("+ "), complete::u64), Operation::Addition).parse(input)
+}
+
+fn parse_multiplication(input: &str) -> IResult<&str, Operation> {
+    map(
+        preceded(tag("* "), complete::u64),
+        Operation::Multiplication,
+    )
+    .parse(input)
+}
+
+fn parse_self_multiplication(input: &str) -> IResult<&str, Operation> {
+    value(Operation::Squared, tag("* old")).parse(input)
+}
+
+fn parse_test_div_line(input: &str) -> IResult<&str, u64> {
+    terminated(
+        preceded(tag("  Test: divisible by "), complete::u64),
+        line_ending,
+    )
+    .parse(input)
+}
+
+fn parse_target_true_line(input: &str) -> IResult<&str, usize> {
+    map(
+        terminated(
+            preceded(tag("    If true: throw to monkey "), complete::u8),
+            line_ending,
+        ),
+        |id| id as usize,
+    )
+    .parse(input)
+}
+
+fn parse_target_false(input: &str) -> IResult<&str, usize> {
+    map(
+        preceded(tag("    If false: throw to monkey "), complete::u8),
+        |id| id as usize,
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<Monkey>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 10605);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        assert_eq!(part_one(&parse_input(load::<Day11>(None)?)?)?, 90882);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 2713310158);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 10605);
+            assert_eq!(part_two(&input)?, 2713310158);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        assert_eq!(part_two(&parse_input(load::<Day11>(None)?)?)?, 30893109657);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_monkeys() {
+        let monkey = |target_true, test_div| {
+            Monkey::new(0, vec![79], Operation::Squared, test_div, target_true, 0)
+        };
+        assert!(matches!(
+            part_one(&[monkey(1, 23)]),
+            Err(Error::NoSuchMonkey {
+                monkey: 0,
+                target: 1
+            })
+        ));
+        assert!(matches!(
+            part_two(&[monkey(0, 0)]),
+            Err(Error::DivisionByZero(0))
+        ));
+        assert!(matches!(
+            part_one(&[monkey(0, 23)]),
+            Err(Error::WorryOverflow(0))
+        ));
+        assert!(matches!(
+            part_two(&[monkey(0, 23)]),
+            Err(Error::TooFewMonkeys(1))
+        ));
+    }
+}
@@ -0,0 +1,132 @@
+//! Property tests checking the monkey business against a queue-based simulation on random groups
+//! of monkeys.
+
+use super::*;
+use proptest::prelude::*;
+use std::collections::{BTreeSet, VecDeque};
+
+const DIVISORS: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+#[derive(Clone, Debug)]
+struct Spec {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    targets: (usize, usize),
+}
+
+/// Between two and five monkeys, each throwing to two others.
+fn monkeys() -> impl Strategy<Value = Vec<Spec>> {
+    (2..6usize).prop_flat_map(|count| {
+        let operation = prop_oneof![
+            (1..10u64).prop_map(Operation::Addition),
+            (2..20u64).prop_map(Operation::Multiplication),
+            Just(Operation::Squared),
+        ];
+        let monkey = (
+            prop::collection::vec(1..100u64, 1..5),
+            operation,
+            prop::sample::select(&DIVISORS[..]),
+            (1..count, 1..count),
+        );
+        prop::collection::vec(monkey, count).prop_map(move |monkeys| {
+            monkeys
+                .into_iter()
+                .enumerate()
+                .map(|(id, (items, operation, divisor, (a, b)))| Spec {
+                    items,
+                    operation,
+                    divisor,
+                    // Offsetting by at least one never lands on the monkey itself.
+                    targets: ((id + a) % count, (id + b) % count),
+                })
+                .collect()
+        })
+    })
+}
+
+fn render(monkeys: &[Spec]) -> &'static str {
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(id, monkey)| {
+            let items = monkey
+                .items
+                .iter()
+                .map(u64::to_string)
+                .collect::<Vec<String>>()
+                .join(", ");
+            let operation = match monkey.operation {
+                Operation::Addition(x) => format!("+ {x}"),
+                Operation::Multiplication(x) => format!("* {x}"),
+                Operation::Squared => "* old".to_owned(),
+            };
+            format!(
+                "Monkey {id}:\n  Starting items: {items}\n  Operation: new = old {operation}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                monkey.divisor, monkey.targets.0, monkey.targets.1
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+        .leak()
+}
+
+/// Plays `rounds` rounds with every monkey throwing its items in turn, and multiplies how often
+/// the two busiest monkeys inspected an item. `None` if a worry level overflows or fewer than two
+/// monkeys ever get to inspect an item.
+fn business(monkeys: &[Spec], rounds: usize, relief: impl Fn(u64) -> u64) -> Option<u64> {
+    let mut queues = monkeys
+        .iter()
+        .map(|monkey| monkey.items.iter().copied().collect::<VecDeque<u64>>())
+        .collect::<Vec<VecDeque<u64>>>();
+    let mut inspected = vec![0u64; monkeys.len()];
+    for _ in 0..rounds {
+        for (id, monkey) in monkeys.iter().enumerate() {
+            while let Some(item) = queues[id].pop_front() {
+                inspected[id] += 1;
+                let worry = relief(monkey.operation.apply(item)?);
+                let target = if worry.is_multiple_of(monkey.divisor) {
+                    monkey.targets.0
+                } else {
+                    monkey.targets.1
+                };
+                queues[target].push_back(worry);
+            }
+        }
+    }
+
+    inspected.retain(|count| *count > 0);
+    inspected.sort_unstable();
+    match inspected[..] {
+        [.., second, first] => Some(first * second),
+        _ => None,
+    }
+}
+
+proptest! {
+    #[test]
+    fn part_one_divides_worry_by_three(monkeys in monkeys()) {
+        let input = parse_input(render(&monkeys)).unwrap();
+        prop_assert_eq!(part_one(&input).ok(), business(&monkeys, 20, |worry| worry / 3));
+    }
+}
+
+proptest! {
+    // Ten thousand rounds make every case slow, so fewer of them are tried.
+    #![proptest_config(ProptestConfig::with_cases(16))]
+
+    #[test]
+    fn part_two_keeps_worry_in_check(monkeys in monkeys()) {
+        let input = parse_input(render(&monkeys)).unwrap();
+        // Every divisor is prime, so their product is also their least common multiple.
+        let modulus = monkeys
+            .iter()
+            .map(|monkey| monkey.divisor)
+            .collect::<BTreeSet<u64>>()
+            .iter()
+            .product::<u64>();
+        prop_assert_eq!(part_two(&input).ok(), business(&monkeys, 10_000, |worry| worry % modulus));
+    }
+}
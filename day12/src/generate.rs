@@ -0,0 +1,67 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day12;
+
+impl Generate for Day12 {
+    /// A heightmap `size` squares wide, at least the 26 a climb from `a` to `z` takes, and half
+    /// as tall. A trail winds from `S` in the left column to `E` in the right one, climbing
+    /// steadily enough to be walked; the rest of the heights are random.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let width = size.max(26);
+        let height = (width / 2).max(1);
+        let mut map = (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| rng.range(b'a'..=b'z'))
+                    .collect::<Vec<u8>>()
+            })
+            .collect::<Vec<Vec<u8>>>();
+
+        // Heading right, sometimes turning up or down for a while first. Every square on the
+        // trail is new, as the trail never goes back left or turns around in a column.
+        let mut trail = vec![];
+        let mut y = rng.range(0..=height - 1);
+        for x in 0..width {
+            let to = if rng.chance(0.3) {
+                rng.range(0..=height - 1)
+            } else {
+                y
+            };
+            while y != to {
+                trail.push((x, y));
+                y = if to > y { y + 1 } else { y - 1 };
+            }
+            trail.push((x, y));
+        }
+
+        // The trail is at least 26 squares long, so climbing evenly along it never goes up more
+        // than one at a time.
+        let last = trail.len() - 1;
+        for (i, (x, y)) in trail.iter().enumerate() {
+            map[*y][*x] = b'a' + (i * 25 / last) as u8;
+        }
+        map[trail[0].1][trail[0].0] = b'S';
+        map[trail[last].1][trail[last].0] = b'E';
+
+        map.into_iter()
+            .map(|row| String::from_utf8(row).unwrap() + "\n")
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day12>(Day12::generate(seed, 60).leak())?;
+        }
+
+        Ok(())
+    }
+}
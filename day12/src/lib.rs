@@ -0,0 +1,172 @@
+use anyhow::Result;
+use aoc_core::{
+    grid::{Grid, Point},
+    parse::{char_grid, parse_all},
+    Solution,
+};
+use nom::error::context;
+use pathfinding::prelude::bfs;
+
+pub struct Day12;
+
+/// Why the heightmap has no path to the best signal.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The heightmap has no {0:?} marker")]
+    MissingMarker(char),
+    #[error("The best signal can't be reached from {0}")]
+    Unreachable(&'static str),
+}
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day12.txt"));
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &Grid<u8>) -> Result<usize, Error> {
+    let start = find_one(input, b'S')?;
+    let goal = find_one(input, b'E')?;
+
+    let result = bfs(&start, |p| next_steps(input, *p), |p| *p == goal)
+        .ok_or(Error::Unreachable("the start"))?;
+
+    Ok(result.len() - 1)
+}
+
+pub fn part_two(input: &Grid<u8>) -> Result<usize, Error> {
+    let goal = find_one(input, b'E')?;
+    let starting_points = input
+        .positions(|h| elevation(*h) == b'a')
+        .collect::<Vec<Point>>();
+
+    starting_points
+        .into_iter()
+        .filter_map(|start| bfs(&start, |p| next_steps(input, *p), |p| *p == goal))
+        .map(|v| v.len() - 1)
+        .min()
+        .ok_or(Error::Unreachable("any square at elevation a"))
+}
+
+fn find_one(input: &Grid<u8>, single: u8) -> Result<Point, Error> {
+    input
+        .positions(|h| *h == single)
+        .next()
+        .ok_or(Error::MissingMarker(single as char))
+}
+
+fn next_steps(input: &Grid<u8>, point: Point) -> Vec<Point> {
+    input
+        .neighbours(point)
+        .filter(|next| can_move(input[point], input[*next]))
+        .collect::<Vec<Point>>()
+}
+
+/// The height of a square, where the start is at elevation `a` and the best signal at `z`.
+fn elevation(square: u8) -> u8 {
+    match square {
+        b'S' => b'a',
+        b'E' => b'z',
+        height => height,
+    }
+}
+
+fn can_move(current_height: u8, new_height: u8) -> bool {
+    elevation(new_height) <= elevation(current_height) + 1
+}
+
+pub fn parse_input(input: &'static str) -> Result<Grid<u8>> {
+    parse_all(
+        context(
+            "a heightmap",
+            char_grid(|c| matches!(c, 'a'..='z' | 'E' | 'S').then_some(c as u8)),
+        ),
+        input,
+    )
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 31);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        assert_eq!(part_one(&parse_input(load::<Day12>(None)?)?)?, 425);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 29);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 31);
+            assert_eq!(part_two(&input)?, 29);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        assert_eq!(part_two(&parse_input(load::<Day12>(None)?)?)?, 418);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_heightmaps() -> Result<()> {
+        assert!(matches!(
+            part_one(&parse_input("Sab\nabc\n")?),
+            Err(Error::MissingMarker('E'))
+        ));
+        assert!(matches!(
+            part_one(&parse_input("Sab\nazE\n")?),
+            Err(Error::Unreachable(_))
+        ));
+        assert!(matches!(
+            part_two(&parse_input("Sab\nazE\n")?),
+            Err(Error::Unreachable(_))
+        ));
+
+        Ok(())
+    }
+}
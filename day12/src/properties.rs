@@ -0,0 +1,103 @@
+//! Property tests checking the shortest paths against a plain breadth-first search on random
+//! heightmaps.
+
+use super::*;
+use proptest::prelude::*;
+use std::collections::VecDeque;
+
+/// Heightmaps that rise one level per step towards the best signal, with bumps that block some
+/// of the ways up. Climbing from `a` to `z` takes at least 25 steps, so they are long strips with
+/// the goal near one end and the ground reaching down to `a` towards the other, putting the
+/// start anywhere from out of reach to right next to the goal.
+fn heightmaps() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    (20..50usize, 1..4usize).prop_flat_map(|(width, height)| {
+        (
+            prop::collection::vec(prop_oneof![6 => Just(0u8), 1 => 1..3u8], width * height),
+            (0..4usize, 0..height),
+            (0..width, 0..height),
+        )
+            .prop_filter("the start is on the goal", |(_, goal, start)| goal != start)
+            .prop_map(move |(bumps, goal, start)| {
+                let mut map = (0..height)
+                    .map(|y| {
+                        (0..width)
+                            .map(|x| {
+                                let distance = x.abs_diff(goal.0) + y.abs_diff(goal.1);
+                                let depth = (distance as u8)
+                                    .min(25)
+                                    .saturating_sub(bumps[y * width + x]);
+                                b'z' - depth
+                            })
+                            .collect::<Vec<u8>>()
+                    })
+                    .collect::<Vec<Vec<u8>>>();
+                map[start.1][start.0] = b'S';
+                map[goal.1][goal.0] = b'E';
+                map
+            })
+    })
+}
+
+fn render(map: &[Vec<u8>]) -> &'static str {
+    map.iter()
+        .map(|row| String::from_utf8(row.clone()).unwrap() + "\n")
+        .collect::<String>()
+        .leak()
+}
+
+/// The height of a square as the puzzle describes it: the start is at `a`, the goal at `z`.
+fn height(square: u8) -> u8 {
+    match square {
+        b'S' => b'a',
+        b'E' => b'z',
+        height => height,
+    }
+}
+
+/// The fewest steps from any square matching `start` to the goal, searching backwards from the
+/// goal so every start is covered by one search.
+fn fewest_steps(map: &[Vec<u8>], start: impl Fn(u8) -> bool) -> Option<usize> {
+    let (width, height_) = (map[0].len() as isize, map.len() as isize);
+    let goal = (0..height_)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .find(|(x, y)| map[*y as usize][*x as usize] == b'E')?;
+    let mut steps = vec![vec![None; width as usize]; height_ as usize];
+    steps[goal.1 as usize][goal.0 as usize] = Some(0);
+    let mut queue = VecDeque::from([goal]);
+    while let Some((x, y)) = queue.pop_front() {
+        let here = map[y as usize][x as usize];
+        let distance = steps[y as usize][x as usize]?;
+        if start(here) {
+            return Some(distance);
+        }
+        for (nx, ny) in [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)] {
+            if nx < 0 || ny < 0 || nx >= width || ny >= height_ {
+                continue;
+            }
+            let there = map[ny as usize][nx as usize];
+            if steps[ny as usize][nx as usize].is_none() && height(here) <= height(there) + 1 {
+                steps[ny as usize][nx as usize] = Some(distance + 1);
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    None
+}
+
+proptest! {
+    #[test]
+    fn part_one_climbs_from_the_start(map in heightmaps()) {
+        let input = parse_input(render(&map)).unwrap();
+        prop_assert_eq!(part_one(&input).ok(), fewest_steps(&map, |square| square == b'S'));
+    }
+
+    #[test]
+    fn part_two_climbs_from_the_best_square(map in heightmaps()) {
+        let input = parse_input(render(&map)).unwrap();
+        prop_assert_eq!(
+            part_two(&input).ok(),
+            fewest_steps(&map, |square| height(square) == b'a')
+        );
+    }
+}
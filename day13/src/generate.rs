@@ -0,0 +1,45 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day13;
+
+impl Generate for Day13 {
+    /// `size` pairs of packets, with lists nesting up to four deep.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size.max(1))
+            .map(|_| format!("{}\n{}\n", packet(&mut rng, 1), packet(&mut rng, 1)))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// A list that is `depth` lists deep.
+fn packet(rng: &mut Rng, depth: usize) -> String {
+    let values = (0..rng.range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.chance(0.3) {
+                packet(rng, depth + 1)
+            } else {
+                rng.range(0..=10).to_string()
+            }
+        })
+        .collect::<Vec<String>>();
+
+    format!("[{}]", values.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day13>(Day13::generate(seed, 100).leak())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{paragraphs, parse_all, IResult, InputError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    character::complete::{self, line_ending},
+    combinator::map,
+    error::{context, ErrorKind, FromExternalError},
+    multi::separated_list0,
+    sequence::{delimited, separated_pair},
+    Parser,
+};
+use std::cmp::Ordering;
+
+/// How deeply lists may nest in a packet. Parsing and comparing packets recurse once per level,
+/// so without a limit a hostile input could overflow the stack; real packets nest a few levels.
+const MAX_DEPTH: usize = 64;
+
+pub struct Day13;
+
+/// Any list of packets can be ordered, so no packets are without an answer.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {}
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day13.txt"));
+
+    type Input = Vec<(Node, Node)>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[(Node, Node)]) -> Result<usize, Error> {
+    Ok(input
+        .iter()
+        .enumerate()
+        .filter(|(_, (left, right))| left <= right)
+        .map(|(i, _)| i + 1)
+        .sum())
+}
+
+pub fn part_two(input: &[(Node, Node)]) -> Result<usize, Error> {
+    let first_divider = Node::Nodes(vec![Node::Nodes(vec![Node::Value(2)])]);
+    let second_divider = Node::Nodes(vec![Node::Nodes(vec![Node::Value(6)])]);
+
+    // A divider's position in the sorted packets is one more than the number of packets before
+    // it, and the second divider also comes after the first.
+    let before = |divider: &Node| {
+        input
+            .iter()
+            .flat_map(|(left, right)| [left, right])
+            .filter(|node| *node < divider)
+            .count()
+    };
+    let one = before(&first_divider) + 1;
+    let two = before(&second_divider) + 2;
+
+    Ok(one * two)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Node {
+    Value(u8),
+    Nodes(Vec<Node>),
+}
+
+impl PartialOrd for Node {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Node {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Node::Value(x), Node::Value(y)) => x.cmp(y),
+            (Node::Nodes(x), Node::Nodes(y)) => x.cmp(y),
+            (Node::Value(_), Node::Nodes(_)) => Node::Nodes(vec![self.clone()]).cmp(other),
+            (Node::Nodes(_), Node::Value(_)) => self.cmp(&Node::Nodes(vec![other.clone()])),
+        }
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<(Node, Node)>> {
+    paragraphs(parse_pair).parse(input)
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (Node, Node)> {
+    context(
+        "a pair of packets",
+        separated_pair(parse_line, line_ending, parse_line),
+    )
+    .parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Node> {
+    parse_list(input, 1)
+}
+
+/// A list that is the `depth`th one its packet has opened without closing.
+fn parse_list(input: &str, depth: usize) -> IResult<&str, Node> {
+    if depth > MAX_DEPTH {
+        complete::char('[').parse(input)?;
+        return Err(nom::Err::Failure(InputError::from_external_error(
+            input,
+            ErrorKind::TooLarge,
+            format!("lists nest more than {MAX_DEPTH} deep"),
+        )));
+    }
+
+    context(
+        "a list",
+        map(
+            delimited(
+                complete::char('['),
+                separated_list0(complete::char(','), |input| parse_value(input, depth + 1)),
+                complete::char(']'),
+            ),
+            Node::Nodes,
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_value(input: &str, depth: usize) -> IResult<&str, Node> {
+    alt((
+        |input| parse_list(input, depth),
+        map(complete::u8, Node::Value),
+    ))
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<(Node, Node)>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        assert_eq!(part_one(&parse_input(load::<Day13>(None)?)?)?, 5196);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 140);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 13);
+            assert_eq!(part_two(&input)?, 140);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        assert_eq!(part_two(&parse_input(load::<Day13>(None)?)?)?, 22134);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_nesting_limit() -> Result<()> {
+        let nested = |depth| "[".repeat(depth) + "1" + &"]".repeat(depth);
+        let input = format!("{}\n[]\n", nested(MAX_DEPTH));
+        assert_eq!(part_one(&parse_input(input.leak())?)?, 0);
+
+        let input = format!("{}\n[]\n", nested(100_000));
+        let error = parse_input(input.leak()).unwrap_err().to_string();
+        assert!(error.contains("column 65: lists nest more than 64 deep"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+//! Property tests checking the packet order against a comparison written straight from the rules
+//! on random packets.
+
+use super::*;
+use proptest::prelude::*;
+use std::cmp::Ordering;
+
+fn packets() -> impl Strategy<Value = Node> {
+    let leaf = (0..11u8).prop_map(Node::Value);
+    let node = leaf.prop_recursive(4, 24, 4, |inner| {
+        prop::collection::vec(inner, 0..4).prop_map(Node::Nodes)
+    });
+    prop::collection::vec(node, 0..4).prop_map(Node::Nodes)
+}
+
+fn pairs() -> impl Strategy<Value = Vec<(Node, Node)>> {
+    prop::collection::vec((packets(), packets()), 1..20)
+}
+
+fn packet(node: &Node) -> String {
+    match node {
+        Node::Value(value) => value.to_string(),
+        Node::Nodes(nodes) => format!(
+            "[{}]",
+            nodes.iter().map(packet).collect::<Vec<String>>().join(",")
+        ),
+    }
+}
+
+fn render(pairs: &[(Node, Node)]) -> &'static str {
+    pairs
+        .iter()
+        .map(|(left, right)| format!("{}\n{}\n", packet(left), packet(right)))
+        .collect::<Vec<String>>()
+        .join("\n")
+        .leak()
+}
+
+/// Compares two packets item by item, turning integers into lists when only one side is a list.
+fn compare(left: &Node, right: &Node) -> Ordering {
+    match (left, right) {
+        (Node::Value(left), Node::Value(right)) => left.cmp(right),
+        (Node::Value(_), Node::Nodes(_)) => compare(&Node::Nodes(vec![left.clone()]), right),
+        (Node::Nodes(_), Node::Value(_)) => compare(left, &Node::Nodes(vec![right.clone()])),
+        (Node::Nodes(left), Node::Nodes(right)) => {
+            let mut i = 0;
+            loop {
+                match (left.get(i), right.get(i)) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(l), Some(r)) => match compare(l, r) {
+                        Ordering::Equal => i += 1,
+                        decided => return decided,
+                    },
+                }
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn part_one_sums_ordered_pairs(pairs in pairs()) {
+        let input = parse_input(render(&pairs)).unwrap();
+        prop_assert_eq!(&input, &pairs);
+        let expected = pairs
+            .iter()
+            .zip(1..)
+            .filter(|((left, right), _)| compare(left, right) != Ordering::Greater)
+            .map(|(_, index)| index)
+            .sum::<usize>();
+        prop_assert_eq!(part_one(&input)?, expected);
+    }
+
+    #[test]
+    fn part_two_finds_the_dividers_after_sorting(pairs in pairs()) {
+        let input = parse_input(render(&pairs)).unwrap();
+        let dividers = [2, 6].map(|value| Node::Nodes(vec![Node::Nodes(vec![Node::Value(value)])]));
+        // Packets equal to a divider go after it: the dividers come first and the sort is stable.
+        let mut packets = dividers
+            .into_iter()
+            .enumerate()
+            .map(|(divider, packet)| (Some(divider), packet))
+            .chain(pairs.into_iter().flat_map(|(left, right)| [(None, left), (None, right)]))
+            .collect::<Vec<(Option<usize>, Node)>>();
+        packets.sort_by(|(_, left), (_, right)| compare(left, right));
+        let position = |divider| {
+            packets.iter().position(|(tag, _)| *tag == Some(divider)).unwrap() + 1
+        };
+        prop_assert_eq!(part_two(&input)?, position(0) * position(1));
+    }
+}
@@ -0,0 +1,158 @@
+//! Frames of sand falling into a cave: the rock, the sand that came to rest and the grain that is
+//! falling, as text for a terminal or as an animated GIF.
+
+use anyhow::Result;
+use gif::{Encoder, Frame, Repeat};
+use std::io::Write;
+
+use crate::{Sand, Tile};
+
+/// Moves the cursor to the top left corner, so each frame draws over the one before.
+pub const HOME: &str = "\x1b[H";
+/// Clears the terminal, before the first frame.
+pub const CLEAR: &str = "\x1b[2J";
+
+const ROCK: &str = "\x1b[90m#";
+const SAND: &str = "\x1b[33mo";
+const FALLING: &str = "\x1b[91m+";
+const AIR: &str = " ";
+const RESET: &str = "\x1b[0m";
+
+/// The cave as coloured text, one line per row.
+pub fn ansi(sand: &Sand) -> String {
+    let grid = sand.grid();
+    let mut text = String::with_capacity((grid.width() * 6 + 5) * grid.height());
+    for (y, row) in grid.rows().enumerate() {
+        for (x, cell) in row.iter().enumerate() {
+            text.push_str(if sand.falling() == Some((x, y)) {
+                FALLING
+            } else {
+                match cell {
+                    Some(Tile::Rock) => ROCK,
+                    Some(Tile::Sand) => SAND,
+                    None => AIR,
+                }
+            });
+        }
+        text.push_str(RESET);
+        text.push('\n');
+    }
+
+    text
+}
+
+/// The colours of the cells, indexed by their place in the palette.
+const PALETTE: [u8; 12] = [
+    20, 20, 28, // air
+    120, 120, 130, // rock
+    230, 190, 90, // sand
+    240, 70, 50, // falling
+];
+
+/// An animated GIF of sand falling into a cave, `scale` pixels to a cell and one frame at a time.
+pub struct Gif<W: Write> {
+    encoder: Encoder<W>,
+    scale: usize,
+    /// How long each frame shows, in hundredths of a second.
+    delay: u16,
+}
+
+impl<W: Write> Gif<W> {
+    pub fn new(writer: W, sand: &Sand, scale: usize, delay: u16) -> Result<Self> {
+        let grid = sand.grid();
+        let side = |cells: usize| u16::try_from(cells * scale);
+        let mut encoder =
+            Encoder::new(writer, side(grid.width())?, side(grid.height())?, &PALETTE)?;
+        encoder.set_repeat(Repeat::Infinite)?;
+
+        Ok(Gif {
+            encoder,
+            scale,
+            delay,
+        })
+    }
+
+    /// Adds the cave as it is now as the next frame.
+    pub fn frame(&mut self, sand: &Sand) -> Result<()> {
+        let grid = sand.grid();
+        let width = grid.width() * self.scale;
+        let mut pixels = Vec::with_capacity(width * grid.height() * self.scale);
+        for (y, row) in grid.rows().enumerate() {
+            let line = row
+                .iter()
+                .enumerate()
+                .flat_map(|(x, cell)| {
+                    let color = if sand.falling() == Some((x, y)) {
+                        3
+                    } else {
+                        match cell {
+                            None => 0,
+                            Some(Tile::Rock) => 1,
+                            Some(Tile::Sand) => 2,
+                        }
+                    };
+                    [color].repeat(self.scale)
+                })
+                .collect::<Vec<u8>>();
+            for _ in 0..self.scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+
+        let height = grid.height() * self.scale;
+        let mut frame = Frame::from_indexed_pixels(width as u16, height as u16, pixels, None);
+        frame.delay = self.delay;
+        self.encoder.write_frame(&frame)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, Bounds, SOURCE};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_ansi() -> Result<()> {
+        let mut sand = Sand::new(&parse_input(TESTDATA)?, &[SOURCE], Bounds::Abyss)?;
+        let frame = ansi(&sand);
+        assert_eq!(frame.lines().count(), sand.grid().height());
+        assert_eq!(frame.matches(ROCK).count(), 20);
+        assert_eq!(frame.matches(FALLING).count(), 1);
+        assert_eq!(frame.matches(SAND).count(), 0);
+
+        while sand.step() {}
+        let frame = ansi(&sand);
+        assert_eq!(frame.matches(SAND).count(), 24);
+        assert_eq!(frame.matches(FALLING).count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gif() -> Result<()> {
+        let cave = parse_input(TESTDATA)?;
+        let mut sand = Sand::new(&cave, &[SOURCE], Bounds::floor_below(&cave)?)?;
+        let mut image = vec![];
+        let mut gif = Gif::new(&mut image, &sand, 2, 5)?;
+        gif.frame(&sand)?;
+        while sand.step() {}
+        gif.frame(&sand)?;
+        drop(gif);
+
+        let mut decoder = gif::Decoder::new(image.as_slice())?;
+        assert_eq!(decoder.width() as usize, sand.grid().width() * 2);
+        assert_eq!(decoder.height() as usize, sand.grid().height() * 2);
+        let mut frames = 0;
+        while let Some(frame) = decoder.read_next_frame()? {
+            assert_eq!(frame.delay, 5);
+            frames += 1;
+        }
+        assert_eq!(frames, 2);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,104 @@
+use anyhow::{Error, Result};
+use clap::Parser;
+use day14::{
+    animate::{self, Gif},
+    Bounds, Day14, Sand, SOURCE,
+};
+use std::{
+    fs::File,
+    io::{self, BufWriter, Write},
+    num::NonZeroUsize,
+    path::PathBuf,
+    thread,
+    time::Duration,
+};
+
+#[derive(Parser)]
+#[command(about = "Shows the sand of day 14 falling, in the terminal or as an animated GIF")]
+struct Args {
+    /// The puzzle input: a path, or `-` to read stdin. Defaults to `inputs/day14.txt`
+    input: Option<String>,
+
+    /// Pour onto the floor of part two instead of into the abyss of part one
+    #[arg(long)]
+    floor: bool,
+
+    /// Where sand pours in, as `x,y`; repeat to pour from several places in turn
+    #[arg(long = "source", value_parser = parse_source, default_value = "500,0")]
+    sources: Vec<(usize, usize)>,
+
+    /// How many rows grains fall between frames; the real input takes millions of them
+    #[arg(long, default_value = "1")]
+    steps: NonZeroUsize,
+
+    /// How many frames to show per second; GIFs play at most 50
+    #[arg(long, default_value_t = 30)]
+    fps: u16,
+
+    /// Save an animated GIF here instead of playing the animation in the terminal
+    #[arg(long)]
+    gif: Option<PathBuf>,
+
+    /// How many pixels wide and high each cell of the GIF is
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let cave = day14::parse_input(aoc_core::input::load::<Day14>(args.input.as_deref())?)?;
+    let bounds = if args.floor {
+        Bounds::floor_below(&cave)?
+    } else {
+        Bounds::Abyss
+    };
+    let mut sand = Sand::new(&cave, &args.sources, bounds)?;
+    let fps = args.fps.max(1);
+
+    match args.gif {
+        Some(path) => {
+            let file = BufWriter::new(File::create(&path)?);
+            // GIF delays count hundredths of a second, and viewers play anything below two at
+            // their own, much slower, default speed.
+            let delay = (100 / fps).max(2);
+            let mut gif = Gif::new(file, &sand, args.scale, delay)?;
+            gif.frame(&sand)?;
+            while advance(&mut sand, args.steps) {
+                gif.frame(&sand)?;
+            }
+            gif.frame(&sand)?;
+            println!("Saved {}", path.display());
+        }
+        None => {
+            let delay = Duration::from_secs(1) / fps.into();
+            let mut stdout = io::stdout().lock();
+            write!(stdout, "{}{}", animate::CLEAR, animate::HOME)?;
+            write!(stdout, "{}", animate::ansi(&sand))?;
+            while advance(&mut sand, args.steps) {
+                write!(stdout, "{}{}", animate::HOME, animate::ansi(&sand))?;
+                stdout.flush()?;
+                thread::sleep(delay);
+            }
+            write!(stdout, "{}{}", animate::HOME, animate::ansi(&sand))?;
+        }
+    }
+    println!("{} units of sand came to rest", sand.resting());
+
+    Ok(())
+}
+
+fn parse_source(s: &str) -> Result<(usize, usize)> {
+    s.split_once(',')
+        .and_then(|(x, y)| Some((x.trim().parse().ok()?, y.trim().parse().ok()?)))
+        .ok_or_else(|| {
+            Error::msg(format!(
+                "{s} is not a position like {},{}",
+                SOURCE.0, SOURCE.1
+            ))
+        })
+}
+
+/// Lets the sand fall `steps` rows, returning `false` once it stopped.
+fn advance(sand: &mut Sand, steps: NonZeroUsize) -> bool {
+    (0..steps.get()).all(|_| sand.step())
+}
@@ -0,0 +1,51 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day14;
+
+impl Generate for Day14 {
+    /// A scan of `size` paths of rock below the source of the sand, each turning a few times.
+    /// The cave gets deeper with more paths, up to the depth of the puzzle's.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+        let depth = (10 + 5 * size as i32).min(180);
+
+        (0..size.max(1))
+            .map(|_| {
+                let mut point: (i32, i32) = (rng.range(470..=530), rng.range(10..=depth - 10));
+                let mut points = vec![point];
+                let horizontal = rng.chance(0.5);
+                for turn in 0..rng.range(1..=4) {
+                    let length = rng.range(1..=8) * *rng.pick(&[-1, 1]);
+                    point = if (turn % 2 == 0) == horizontal {
+                        ((point.0 + length).clamp(400, 600), point.1)
+                    } else {
+                        (point.0, (point.1 + length).clamp(1, depth))
+                    };
+                    points.push(point);
+                }
+
+                points
+                    .iter()
+                    .map(|(x, y)| format!("{x},{y}"))
+                    .collect::<Vec<String>>()
+                    .join(" -> ")
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day14>(Day14::generate(seed, 10).leak())?;
+        }
+
+        Ok(())
+    }
+}
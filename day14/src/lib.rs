@@ -0,0 +1,217 @@
+use anyhow::Result;
+use aoc_core::{
+    grid::{Point, SparseGrid},
+    parse::{coordinate, lines, parse_all, IResult},
+    Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    combinator::{map, map_res},
+    error::context,
+    multi::separated_list1,
+    Parser,
+};
+use std::{
+    cmp::{max, min},
+    fmt::{self, Display},
+};
+
+pub struct Day14;
+
+/// Why sand can't be poured into the cave.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The cave has no rock")]
+    NoRock,
+    #[error("Sand would come to rest left of x = 0, outside of the cave")]
+    PastLeftEdge,
+    #[error("There is no source of sand")]
+    NoSource,
+    #[error("The source at {0:?} is outside of the floor and walls")]
+    SourceOutside(Point),
+}
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day14.txt"));
+
+    type Input = Cave;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(cave: &Cave) -> Result<usize, Error> {
+    Ok(Sand::new(cave, &[SOURCE], Bounds::Abyss)?.pour().total())
+}
+
+pub fn part_two(cave: &Cave) -> Result<usize, Error> {
+    Ok(Sand::new(cave, &[SOURCE], Bounds::floor_below(cave)?)?
+        .pour()
+        .total())
+}
+
+/// Where the sand of the puzzle pours in.
+pub const SOURCE: Point = (500, 0);
+
+/// How far from the origin rock can be. Scans are a few hundred units across; the limit keeps a
+/// bogus scan from filling memory with rock before it can be rejected.
+const MAX_COORDINATE: usize = 10_000;
+
+pub type Cave = SparseGrid<Tile>;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Tile {
+    Rock,
+    Sand,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+fn make_cave(paths: Vec<Vec<(Point, Point)>>) -> Cave {
+    paths
+        .into_iter()
+        .flatten()
+        .flat_map(|(from, to)| {
+            if from.0 == to.0 {
+                // vertical
+                (min(from.1, to.1)..=max(from.1, to.1))
+                    .map(|y| (from.0, y))
+                    .collect::<Vec<Point>>()
+            } else {
+                // horizontal
+                (min(from.0, to.0)..=max(from.0, to.0))
+                    .map(|x| (x, from.1))
+                    .collect::<Vec<Point>>()
+            }
+        })
+        .map(|point| (point, Tile::Rock))
+        .collect::<Cave>()
+}
+fn parse(input: &str) -> IResult<&str, Cave> {
+    map(lines(parse_line), make_cave).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<(Point, Point)>> {
+    context(
+        "a path of rock",
+        map_res(separated_list1(tag(" -> "), parse_point), |v| {
+            v.windows(2)
+                .map(|w| match (w[0], w[1]) {
+                    (from, to) if from.0 == to.0 || from.1 == to.1 => Ok((from, to)),
+                    (from, to) => Err(format!("{from:?} -> {to:?} is not a straight line")),
+                })
+                .collect::<std::result::Result<Vec<(Point, Point)>, String>>()
+        }),
+    )
+    .parse(input)
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point> {
+    context(
+        "a point",
+        map_res(coordinate(complete::char(',')), |point: Point| {
+            if point.0 > MAX_COORDINATE || point.1 > MAX_COORDINATE {
+                Err(format!("{point:?} is further out than {MAX_COORDINATE}"))
+            } else {
+                Ok(point)
+            }
+        }),
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Cave> {
+    parse_all(parse, input)
+}
+
+pub mod animate;
+mod generate;
+mod sand;
+
+pub use sand::{Bounds, Poured, Sand};
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 24);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        assert_eq!(part_one(&parse_input(load::<Day14>(None)?)?)?, 913);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 93);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 24);
+            assert_eq!(part_two(&input)?, 93);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        assert_eq!(part_two(&parse_input(load::<Day14>(None)?)?)?, 30762);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_caves() -> Result<()> {
+        assert!(matches!(part_one(&Cave::new()), Err(Error::NoRock)));
+        // Sand falls off the rock into the abyss on both sides, left of x = 0 too.
+        assert_eq!(part_one(&parse_input("0,4 -> 2,4\n")?)?, 0);
+        assert!(matches!(
+            part_two(&parse_input("498,600 -> 502,600\n")?),
+            Err(Error::PastLeftEdge)
+        ));
+        assert!(parse_input("498,4 -> 500,6\n").is_err());
+        assert!(parse_input("498,4 -> 498496,4\n").is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+//! Property tests checking the falling sand against a simulation on a plain 2D array with random
+//! rock formations.
+
+use super::*;
+use proptest::prelude::*;
+
+/// Paths of horizontal and vertical lines of rock below and around the source.
+fn paths() -> impl Strategy<Value = Vec<Vec<Point>>> {
+    let turn = (any::<bool>(), 490..=510usize, 1..=12usize);
+    let path = (
+        (490..=510usize, 1..=12usize),
+        prop::collection::vec(turn, 1..4),
+    )
+        .prop_map(|(start, turns)| {
+            let mut points = vec![start];
+            for (horizontal, x, y) in turns {
+                let (last_x, last_y) = points[points.len() - 1];
+                points.push(if horizontal { (x, last_y) } else { (last_x, y) });
+            }
+            points
+        });
+    prop::collection::vec(path, 1..6)
+}
+
+fn render(paths: &[Vec<Point>]) -> &'static str {
+    paths
+        .iter()
+        .map(|path| {
+            let points = path
+                .iter()
+                .map(|(x, y)| format!("{x},{y}"))
+                .collect::<Vec<String>>();
+            points.join(" -> ") + "\n"
+        })
+        .collect::<String>()
+        .leak()
+}
+
+/// Drops sand from each source in turn, each until a unit falls below all rock, or comes to rest
+/// on the floor two rows below it if there is one, and counts the units that came to rest.
+fn pour(paths: &[Vec<Point>], sources: &[Point], floor: bool) -> Vec<usize> {
+    let bottom = paths.iter().flatten().map(|(_, y)| *y).max().unwrap();
+    let (width, height) = (1100, bottom + 3);
+    let mut blocked = vec![vec![false; width]; height];
+    for path in paths {
+        for pair in path.windows(2) {
+            let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+            for row in &mut blocked[y1.min(y2)..=y1.max(y2)] {
+                row[x1.min(x2)..=x1.max(x2)].fill(true);
+            }
+        }
+    }
+    if floor {
+        blocked[bottom + 2] = vec![true; width];
+    }
+
+    let mut resting = vec![0; sources.len()];
+    let mut pouring = vec![true; sources.len()];
+    while pouring.contains(&true) {
+        for (index, &source) in sources.iter().enumerate() {
+            if !pouring[index] || blocked[source.1][source.0] {
+                pouring[index] = false;
+                continue;
+            }
+            let (mut x, mut y) = source;
+            let fell = loop {
+                if y + 1 == height {
+                    break true;
+                }
+                match [x, x - 1, x + 1]
+                    .into_iter()
+                    .find(|next| !blocked[y + 1][*next])
+                {
+                    Some(next) => (x, y) = (next, y + 1),
+                    None => break false,
+                }
+            };
+            if fell {
+                pouring[index] = false;
+            } else {
+                blocked[y][x] = true;
+                resting[index] += 1;
+            }
+        }
+    }
+
+    resting
+}
+
+fn sources() -> impl Strategy<Value = Vec<Point>> {
+    // Rock starts in row 1, so even the highest floor is below them.
+    prop::collection::vec((495..=505usize, 0..=2usize), 1..4)
+}
+
+proptest! {
+    #[test]
+    fn part_one_stops_when_sand_falls_past_the_rock(paths in paths()) {
+        let input = parse_input(render(&paths)).unwrap();
+        prop_assert_eq!(part_one(&input)?, pour(&paths, &[SOURCE], false)[0]);
+    }
+
+    #[test]
+    fn part_two_stops_when_the_source_is_blocked(paths in paths()) {
+        let input = parse_input(render(&paths)).unwrap();
+        prop_assert_eq!(part_two(&input)?, pour(&paths, &[SOURCE], true)[0]);
+    }
+
+    #[test]
+    fn sources_take_turns(paths in paths(), sources in sources(), floor: bool) {
+        let input = parse_input(render(&paths)).unwrap();
+        let bounds = if floor { Bounds::floor_below(&input)? } else { Bounds::Abyss };
+        let poured = Sand::new(&input, &sources, bounds)?.pour();
+        prop_assert_eq!(poured.resting, pour(&paths, &sources, floor));
+    }
+}
@@ -0,0 +1,349 @@
+//! Sand pouring into a cave from one or more sources, until none of them can pour any more.
+
+use aoc_core::grid::{Grid, Point};
+
+use crate::{Cave, Error, Tile};
+
+/// What stops sand that gets past the rock.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Bounds {
+    /// Nothing: sand that falls past the lowest rock falls forever.
+    Abyss,
+    /// A floor in row `y`, as wide as it needs to be.
+    Floor(usize),
+    /// A box: walls in columns `left` and `right` and a floor in row `floor` between them.
+    Walls {
+        left: usize,
+        right: usize,
+        floor: usize,
+    },
+}
+
+impl Bounds {
+    /// The floor of part two, two rows below the lowest rock.
+    pub fn floor_below(cave: &Cave) -> Result<Self, Error> {
+        let (_, (_, max_y)) = cave.bounds().ok_or(Error::NoRock)?;
+
+        Ok(Bounds::Floor(max_y + 2))
+    }
+}
+
+/// Sand falling into a cave, on a dense grid just big enough for everywhere it can get to before
+/// it comes to rest or falls into the abyss.
+///
+/// The sources take turns: each drops a grain and waits for it to come to rest before the next
+/// source drops one. A source stops once its grain falls into the abyss or the sand piles up to
+/// it.
+pub struct Sand {
+    grid: Grid<Option<Tile>>,
+    /// The x in the cave of the grid's first column: -1 when sand can fall into the abyss left of
+    /// x = 0.
+    left: isize,
+    bounds: Bounds,
+    sources: Vec<Source>,
+    /// The source whose grain is falling.
+    current: usize,
+}
+
+struct Source {
+    /// The way the last grain fell, from the source to where it came to rest. The next grain falls
+    /// the same way up to the last position that's still open, so it starts from there. Empty
+    /// once the source stopped.
+    path: Vec<Point>,
+    resting: usize,
+}
+
+/// How pouring sand into a cave ended.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Poured {
+    /// How many grains from each source came to rest, in the order of the sources.
+    pub resting: Vec<usize>,
+    /// The rock and the sand that came to rest, with the grid's first column at `left` in the
+    /// cave.
+    pub grid: Grid<Option<Tile>>,
+    pub left: isize,
+}
+
+impl Poured {
+    /// How many grains came to rest from all sources together.
+    pub fn total(&self) -> usize {
+        self.resting.iter().sum()
+    }
+}
+
+impl Sand {
+    /// The rock of `cave` without any sand yet, and a grain about to fall from the first of
+    /// `sources`.
+    pub fn new(cave: &Cave, sources: &[Point], bounds: Bounds) -> Result<Self, Error> {
+        if sources.is_empty() {
+            return Err(Error::NoSource);
+        }
+        let ((left, right), height) = match bounds {
+            // Sand beside or below all rock falls forever; a column either side is enough to see
+            // it go, even left of x = 0.
+            Bounds::Abyss => {
+                let ((min_x, _), (max_x, max_y)) = cave.bounds().ok_or(Error::NoRock)?;
+                let min_x = sources.iter().map(|(x, _)| *x).fold(min_x, usize::min);
+                let max_x = sources.iter().map(|(x, _)| *x).fold(max_x, usize::max);
+                let lowest = sources.iter().map(|(_, y)| *y).fold(max_y, usize::max);
+                ((min_x as isize - 1, max_x + 1), lowest + 1)
+            }
+            // The pile on the floor spreads one column either way for every row it falls.
+            Bounds::Floor(floor) => {
+                let mut columns = (usize::MAX, 0);
+                for &(x, y) in sources {
+                    if y >= floor {
+                        return Err(Error::SourceOutside((x, y)));
+                    }
+                    let spread = floor - 1 - y;
+                    if spread > x {
+                        return Err(Error::PastLeftEdge);
+                    }
+                    columns = (columns.0.min(x - spread), columns.1.max(x + spread));
+                }
+                ((columns.0 as isize, columns.1), floor)
+            }
+            Bounds::Walls { left, right, floor } => {
+                if let Some(source) = sources
+                    .iter()
+                    .find(|(x, y)| *x <= left || *x >= right || *y >= floor)
+                {
+                    return Err(Error::SourceOutside(*source));
+                }
+                ((left as isize, right), floor)
+            }
+        };
+
+        // The grid's column of an x in the cave from `left` to `right`.
+        let column = |x: usize| (x as isize - left) as usize;
+        let mut grid = Grid::new(column(right) + 1, height, None);
+        for ((x, y), tile) in cave.iter() {
+            if (left..=right as isize).contains(&(x as isize)) && y < height {
+                grid[(column(x), y)] = Some(*tile);
+            }
+        }
+        if let Bounds::Walls { .. } = bounds {
+            for y in 0..height {
+                grid[(0, y)] = Some(Tile::Rock);
+                grid[(column(right), y)] = Some(Tile::Rock);
+            }
+        }
+        let sources = sources
+            .iter()
+            .map(|(x, y)| {
+                let source = (column(*x), *y);
+                Source {
+                    path: if grid[source].is_none() {
+                        vec![source]
+                    } else {
+                        vec![]
+                    },
+                    resting: 0,
+                }
+            })
+            .collect::<Vec<Source>>();
+        let current = sources
+            .iter()
+            .position(|source| !source.path.is_empty())
+            .unwrap_or_default();
+
+        Ok(Sand {
+            grid,
+            left,
+            bounds,
+            sources,
+            current,
+        })
+    }
+
+    /// Drops grains until every source stopped.
+    pub fn pour(mut self) -> Poured {
+        while self.step() {}
+
+        Poured {
+            resting: self.sources.iter().map(|source| source.resting).collect(),
+            grid: self.grid,
+            left: self.left,
+        }
+    }
+
+    /// Moves the falling grain one row down, or lets it come to rest or fall into the abyss and
+    /// has the next source drop one. Returns `false` once every source stopped.
+    pub fn step(&mut self) -> bool {
+        let path = &mut self.sources[self.current].path;
+        let Some(&(x, y)) = path.last() else {
+            return false;
+        };
+        if y + 1 == self.grid.height() {
+            match self.bounds {
+                Bounds::Abyss => {
+                    path.clear();
+                    return self.next_source();
+                }
+                Bounds::Floor(_) | Bounds::Walls { .. } => return self.rest(),
+            }
+        }
+
+        // Grains only reach the outer columns of the grid in the abyss, where they fall straight
+        // down, or next to walls, so looking past them is never needed.
+        let next = [Some(x), x.checked_sub(1), Some(x + 1)]
+            .into_iter()
+            .flatten()
+            .map(|x| (x, y + 1))
+            .find(|point| self.grid.get(*point) == Some(&None));
+        match next {
+            Some(point) => {
+                path.push(point);
+                true
+            }
+            None => self.rest(),
+        }
+    }
+
+    /// The rock and the sand that came to rest, on a grid whose first column is
+    /// [`Sand::left`] in the cave.
+    pub fn grid(&self) -> &Grid<Option<Tile>> {
+        &self.grid
+    }
+
+    /// The x in the cave of the grid's first column.
+    pub fn left(&self) -> isize {
+        self.left
+    }
+
+    /// Where on the grid the grain that is falling now is.
+    pub fn falling(&self) -> Option<Point> {
+        self.sources[self.current].path.last().copied()
+    }
+
+    /// How many grains came to rest so far, from all sources together.
+    pub fn resting(&self) -> usize {
+        self.sources.iter().map(|source| source.resting).sum()
+    }
+
+    /// Lets the falling grain come to rest where it is.
+    fn rest(&mut self) -> bool {
+        let source = &mut self.sources[self.current];
+        let Some(point) = source.path.pop() else {
+            return false;
+        };
+        self.grid[point] = Some(Tile::Sand);
+        source.resting += 1;
+
+        // The other sources' grains fall the way they did before up to where this one came to
+        // rest, as sand only ever blocks more of the way.
+        for (index, source) in self.sources.iter_mut().enumerate() {
+            if index == self.current {
+                continue;
+            }
+            if let Some(end) = source.path.iter().position(|p| *p == point) {
+                source.path.truncate(end);
+            }
+        }
+
+        self.next_source()
+    }
+
+    /// Moves on to the next source that hasn't stopped, returning `false` if there is none.
+    fn next_source(&mut self) -> bool {
+        let count = self.sources.len();
+        match (1..=count)
+            .map(|offset| (self.current + offset) % count)
+            .find(|index| !self.sources[*index].path.is_empty())
+        {
+            Some(index) => {
+                self.current = index;
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, SOURCE};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_walls() -> Result<(), Error> {
+        let bounds = Bounds::Walls {
+            left: 0,
+            right: 4,
+            floor: 3,
+        };
+        let poured = Sand::new(&Cave::new(), &[(2, 0)], bounds)?.pour();
+        assert_eq!(poured.resting, [7]);
+        assert_eq!(poured.left, 0);
+        let rows = poured
+            .grid
+            .rows()
+            .map(|row| row.iter().filter(|cell| **cell == Some(Tile::Sand)).count())
+            .collect::<Vec<usize>>();
+        assert_eq!(rows, [1, 3, 3]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_abyss_left_of_zero() -> anyhow::Result<()> {
+        let cave = parse_input("0,4 -> 2,4\n")?;
+        let sand = Sand::new(&cave, &[(1, 0)], Bounds::Abyss)?;
+        assert_eq!(sand.left(), -1);
+
+        // The first grain comes to rest in the middle of the rock, the second rolls off its left
+        // end into the abyss.
+        let poured = sand.pour();
+        assert_eq!(poured.resting, [1]);
+        assert_eq!(poured.grid[(2, 3)], Some(Tile::Sand));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sources() -> anyhow::Result<()> {
+        let cave = parse_input(TESTDATA)?;
+        let poured = Sand::new(&cave, &[SOURCE, (496, 0)], Bounds::Floor(11))?.pour();
+        assert_eq!(poured.resting.len(), 2);
+        // Together they fill the same triangle the first source fills on its own, and more.
+        assert!(poured.total() > 93);
+        let sand = poured
+            .grid
+            .iter()
+            .filter(|(_, cell)| **cell == Some(Tile::Sand))
+            .count();
+        assert_eq!(sand, poured.total());
+
+        // A source on top of another's pile stops when that pile reaches it.
+        let poured = Sand::new(&cave, &[SOURCE, (500, 1)], Bounds::Floor(11))?.pour();
+        assert_eq!(poured.total(), 93);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_sources() -> anyhow::Result<()> {
+        let cave = parse_input(TESTDATA)?;
+        assert!(matches!(
+            Sand::new(&cave, &[], Bounds::Abyss),
+            Err(Error::NoSource)
+        ));
+        assert!(matches!(
+            Sand::new(&cave, &[(500, 11)], Bounds::Floor(11)),
+            Err(Error::SourceOutside((500, 11)))
+        ));
+        let walls = Bounds::Walls {
+            left: 490,
+            right: 510,
+            floor: 11,
+        };
+        assert!(matches!(
+            Sand::new(&cave, &[(490, 0)], walls),
+            Err(Error::SourceOutside((490, 0)))
+        ));
+
+        Ok(())
+    }
+}
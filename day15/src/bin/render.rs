@@ -0,0 +1,60 @@
+use anyhow::Result;
+use clap::Parser;
+use day15::{
+    distress_beacon, parse_input,
+    render::{self, Marks},
+    Day15, LINE, MAX,
+};
+use std::{fs::File, io::BufWriter, path::PathBuf};
+
+#[derive(Parser)]
+#[command(about = "Draws the sensors of a day 15 report, e.g. to see why no beacon was found")]
+struct Args {
+    /// The puzzle input: a path, or `-` to read stdin. Defaults to `inputs/day15.txt`
+    input: Option<String>,
+
+    /// Where to save the picture: a PNG if the name ends in `.png`, an SVG otherwise
+    #[arg(long, short)]
+    output: PathBuf,
+
+    /// The line part one counts on; the example uses 10
+    #[arg(long, default_value_t = LINE)]
+    line: i32,
+
+    /// The last row and column part two searches; the example uses 20
+    #[arg(long, default_value_t = MAX)]
+    max: i32,
+
+    /// How many pixels wide or high the picture is, whichever is larger
+    #[arg(long, default_value_t = 800)]
+    size: u32,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let sensors = parse_input(aoc_core::input::load::<Day15>(args.input.as_deref())?)?;
+    let marks = Marks {
+        line: Some(args.line),
+        max: Some(args.max),
+        // Drawing the sensors helps most when there is no answer, so go on without it.
+        distress: distress_beacon(&sensors, args.max).ok(),
+    };
+
+    if args
+        .output
+        .extension()
+        .is_some_and(|extension| extension == "png")
+    {
+        render::png(
+            &sensors,
+            &marks,
+            args.size,
+            BufWriter::new(File::create(&args.output)?),
+        )?;
+    } else {
+        std::fs::write(&args.output, render::svg(&sensors, &marks, args.size))?;
+    }
+    println!("Saved {}", args.output.display());
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::{Coord, Day15, MAX};
+
+impl Generate for Day15 {
+    /// A report of `size` sensors, plus the eight that leave exactly one position in the search
+    /// area uncovered.
+    fn generate(seed: u64, size: usize) -> String {
+        report(&mut Rng::new(seed), size, MAX)
+    }
+}
+
+/// Sensors that cover every position from `(0, 0)` to `(max, max)` but one.
+///
+/// Eight sensors around the gap, further away than `max` straight and diagonally, each reach up
+/// to just before it; together they cover the rest of the area. The other `count` sensors are
+/// placed at random and also stop short of the gap.
+fn report(rng: &mut Rng, count: usize, max: i32) -> String {
+    let gap = (rng.range(0..=max), rng.range(0..=max));
+    let far = max + 1;
+
+    let mut sensors = vec![];
+    for (a, b) in [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ] {
+        let sensor = (gap.0 + a * far, gap.1 + b * far);
+        sensors.push((sensor, beacon(rng, sensor, distance(sensor, gap) - 1)));
+    }
+    while sensors.len() < count + 8 {
+        let sensor = (rng.range(-far..=2 * far), rng.range(-far..=2 * far));
+        let reach = distance(sensor, gap) - 1;
+        if reach > 0 {
+            let reach = rng.range(1..=reach);
+            sensors.push((sensor, beacon(rng, sensor, reach)));
+        }
+    }
+    rng.shuffle(&mut sensors);
+
+    sensors
+        .iter()
+        .map(|((sx, sy), (bx, by))| {
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+        })
+        .collect()
+}
+
+/// A position `distance` away from `sensor`.
+fn beacon(rng: &mut Rng, sensor: Coord, distance: i32) -> Coord {
+    let dx = rng.range(-distance..=distance);
+    let dy = (distance - dx.abs()) * *rng.pick(&[-1, 1]);
+
+    (sensor.0 + dx, sensor.1 + dy)
+}
+
+fn distance(a: Coord, b: Coord) -> i32 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_input, part_two};
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day15>(Day15::generate(seed, 20).leak())?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_one_gap() -> Result<()> {
+        for seed in 0..20 {
+            let input = parse_input(report(&mut Rng::new(seed), 10, 20).leak())?;
+            let covered = |x, y| {
+                input
+                    .0
+                    .iter()
+                    .any(|info| distance(info.sensor, (x, y)) <= distance(info.sensor, info.beacon))
+            };
+            let gaps = (0..=20)
+                .flat_map(|y| (0..=20).map(move |x| (x, y)))
+                .filter(|(x, y)| !covered(*x, *y))
+                .collect::<Vec<Coord>>();
+            assert_eq!(gaps.len(), 1);
+            let (x, y) = gaps[0];
+            assert_eq!(part_two(&input, 20)?, x as i64 * 4_000_000 + y as i64);
+        }
+
+        Ok(())
+    }
+}
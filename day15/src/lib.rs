@@ -0,0 +1,491 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{coordinate, lines, parse_all, IResult},
+    Solution,
+};
+use nom::{bytes::complete::tag, combinator::map, error::context, sequence::preceded, Parser};
+use std::{mem, ops::RangeInclusive};
+
+pub const LINE: i32 = 2_000_000;
+pub const MAX: i32 = 4_000_000;
+
+pub struct Day15;
+
+/// Why the sensors don't pin down the distress beacon.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The beacon could be at any of {0} positions no sensor reaches")]
+    Ambiguous(u64),
+    #[error("The sensors cover every position where the beacon could be")]
+    NoGap,
+    #[error("The sensor at {0:?} reaches positions too far out to keep track of")]
+    CoordinateOverflow(Coord),
+}
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day15.txt"));
+
+    type Input = Sensors;
+    type PartOne = usize;
+    type PartTwo = i64;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input, LINE)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input, MAX)?)
+    }
+}
+
+pub fn part_one(input: &Sensors, line: i32) -> Result<usize, Error> {
+    let covered = input
+        .covered_intervals(line)
+        .iter()
+        .map(|range| (*range.end() as i64 - *range.start() as i64 + 1) as usize)
+        .sum::<usize>();
+    // Every beacon is in reach of its sensor, so it is counted above.
+    let mut beacons = input
+        .0
+        .iter()
+        .filter(|info| info.beacon.1 == line)
+        .map(|info| info.beacon.0)
+        .collect::<Vec<i32>>();
+    beacons.sort_unstable();
+    beacons.dedup();
+
+    Ok(covered - beacons.len())
+}
+
+pub fn part_two(input: &Sensors, max: i32) -> Result<i64, Error> {
+    let (x, y) = distress_beacon(input, max)?;
+
+    Ok(x as i64 * 4_000_000 + y as i64)
+}
+
+/// The only position from `(0, 0)` to `(max, max)` that no sensor reaches.
+pub fn distress_beacon(input: &Sensors, max: i32) -> Result<Coord, Error> {
+    match input.gaps(&Area::square(max)) {
+        Gaps::None => Err(Error::NoGap),
+        Gaps::One(position) => Ok(position),
+        Gaps::Many(areas) => Err(Error::Ambiguous(areas.iter().map(Area::len).sum())),
+    }
+}
+
+pub type Coord = (i32, i32);
+
+#[derive(Clone, Copy, Debug)]
+pub struct SensorInfo {
+    sensor: Coord,
+    beacon: Coord,
+}
+
+impl SensorInfo {
+    pub fn range_on_line(&self, line: i32) -> Option<RangeInclusive<i32>> {
+        let max_dist = self.manhattan_distance();
+        let y_needed = self.sensor.1.abs_diff(line);
+        if y_needed > max_dist {
+            // out of reach
+            return None;
+        }
+        let deviation = (max_dist - y_needed) as i32;
+        Some((self.sensor.0 - deviation)..=(self.sensor.0 + deviation))
+    }
+
+    pub fn manhattan_distance(&self) -> u32 {
+        self.sensor.1.abs_diff(self.beacon.1) + self.sensor.0.abs_diff(self.beacon.0)
+    }
+}
+
+/// All the sensors of a report.
+#[derive(Clone, Debug)]
+pub struct Sensors(Vec<SensorInfo>);
+
+/// A rectangle of positions, including the last row and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Area {
+    pub x: RangeInclusive<i32>,
+    pub y: RangeInclusive<i32>,
+}
+
+impl Area {
+    /// The square from `(0, 0)` to `(max, max)` that part two searches.
+    pub fn square(max: i32) -> Self {
+        Area {
+            x: 0..=max,
+            y: 0..=max,
+        }
+    }
+
+    /// The number of positions in the area.
+    pub fn len(&self) -> u64 {
+        let length = |range: &RangeInclusive<i32>| {
+            (*range.end() as i64 - *range.start() as i64 + 1).max(0) as u64
+        };
+        length(&self.x) * length(&self.y)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// The positions in an area that no sensor reaches.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Gaps {
+    /// The sensors reach every position.
+    None,
+    /// A single position, where the distress beacon must be.
+    One(Coord),
+    /// More than one position, as rectangles that don't overlap, from the top.
+    Many(Vec<Area>),
+}
+
+/// Positions on a line that the sensors reach, merged from the ranges of one or more sensors.
+struct Interval {
+    range: RangeInclusive<i32>,
+    /// How far the ranges it was merged from overlap where they meet, at the least.
+    slack: i32,
+}
+
+/// Buffers reused from line to line, so walking the lines of an area doesn't allocate for each.
+#[derive(Default)]
+struct Scratch {
+    ranges: Vec<RangeInclusive<i32>>,
+    intervals: Vec<Interval>,
+    gaps: Vec<RangeInclusive<i32>>,
+}
+
+impl Sensors {
+    /// The positions on `line` that some sensor reaches, as sorted ranges that neither overlap nor
+    /// touch.
+    pub fn covered_intervals(&self, line: i32) -> Vec<RangeInclusive<i32>> {
+        let mut scratch = Scratch::default();
+        self.merge(line, &mut scratch);
+
+        scratch
+            .intervals
+            .into_iter()
+            .map(|interval| interval.range)
+            .collect()
+    }
+
+    /// The positions in `area` that no sensor reaches.
+    ///
+    /// Walks the lines of the area from the top, joining the same gaps on consecutive lines into
+    /// rectangles. The ranges merged into an interval overlap by some slack, and as each of their
+    /// ends moves at most one position per line, that slack shrinks by at most two per line; so
+    /// after a line without gaps, the next `slack / 2` lines have none either and are skipped.
+    pub fn gaps(&self, area: &Area) -> Gaps {
+        let mut scratch = Scratch::default();
+        let mut done = Vec::<Area>::new();
+        let mut open = Vec::<Area>::new();
+        let mut extended = Vec::<Area>::new();
+        let mut line = *area.y.start();
+        while line <= *area.y.end() {
+            let slack = self.gaps_on_line(line, &area.x, &mut scratch);
+
+            for x in scratch.gaps.drain(..) {
+                match open
+                    .iter()
+                    .position(|gap| gap.x == x && *gap.y.end() as i64 == line as i64 - 1)
+                {
+                    Some(index) => {
+                        let gap = open.swap_remove(index);
+                        extended.push(Area {
+                            y: *gap.y.start()..=line,
+                            ..gap
+                        });
+                    }
+                    None => extended.push(Area { x, y: line..=line }),
+                }
+            }
+            done.append(&mut open);
+            mem::swap(&mut open, &mut extended);
+
+            let Some(next) = line.checked_add(slack / 2 + 1) else {
+                break;
+            };
+            line = next;
+        }
+        done.append(&mut open);
+        done.sort_unstable_by_key(|gap| (*gap.y.start(), *gap.x.start()));
+
+        match done.as_slice() {
+            [] => Gaps::None,
+            [gap] if gap.len() == 1 => Gaps::One((*gap.x.start(), *gap.y.start())),
+            _ => Gaps::Many(done),
+        }
+    }
+
+    /// Fills `scratch.gaps` with the ranges of `x` on `line` that no sensor reaches. Returns the
+    /// slack of the interval that covers all of `x` if there are none.
+    fn gaps_on_line(&self, line: i32, x: &RangeInclusive<i32>, scratch: &mut Scratch) -> i32 {
+        self.merge(line, scratch);
+        let Scratch {
+            intervals, gaps, ..
+        } = scratch;
+        gaps.clear();
+
+        let (first, last) = (*x.start(), *x.end());
+        // The first position from which nothing has been reached yet.
+        let mut next = first as i64;
+        for interval in intervals.iter() {
+            let (start, end) = (*interval.range.start(), *interval.range.end());
+            if (end as i64) < next {
+                continue;
+            }
+            if start > last {
+                break;
+            }
+            if start as i64 > next {
+                gaps.push(next as i32..=start - 1);
+            } else if next == first as i64 && end >= last {
+                let slack = (interval.slack as i64)
+                    .min(first as i64 - start as i64)
+                    .min(end as i64 - last as i64);
+                return slack as i32;
+            }
+            next = end as i64 + 1;
+        }
+        if next <= last as i64 {
+            gaps.push(next as i32..=last);
+        }
+
+        0
+    }
+
+    /// Fills `scratch.intervals` with the ranges of the sensors on `line`, merged.
+    fn merge(&self, line: i32, scratch: &mut Scratch) {
+        let Scratch {
+            ranges, intervals, ..
+        } = scratch;
+        ranges.clear();
+        ranges.extend(self.0.iter().filter_map(|info| info.range_on_line(line)));
+        ranges.sort_unstable_by_key(|range| *range.start());
+
+        intervals.clear();
+        for range in ranges.drain(..) {
+            match intervals.last_mut() {
+                Some(last) if *range.start() as i64 <= *last.range.end() as i64 + 1 => {
+                    if range.end() > last.range.end() {
+                        let overlap = *last.range.end() as i64 + 1 - *range.start() as i64;
+                        last.slack = (last.slack as i64).min(overlap) as i32;
+                        last.range = *last.range.start()..=*range.end();
+                    }
+                }
+                _ => intervals.push(Interval {
+                    range,
+                    slack: i32::MAX,
+                }),
+            }
+        }
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Sensors> {
+    map(lines(parse_line), Sensors).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, SensorInfo> {
+    context(
+        "a sensor report",
+        map(
+            (
+                tag("Sensor at "),
+                parse_coord,
+                tag(": closest beacon is at "),
+                parse_coord,
+            ),
+            |(_, sensor, _, beacon)| SensorInfo { sensor, beacon },
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_coord(input: &str) -> IResult<&str, Coord> {
+    context("a position", preceded(tag("x="), coordinate(tag(", y=")))).parse(input)
+}
+
+/// Parses the report, failing on sensors that reach past the positions an `i32` can hold, so that
+/// nothing measuring their reach overflows.
+pub fn parse_input(input: &'static str) -> Result<Sensors> {
+    let sensors = parse_all(parse, input)?;
+    for info in sensors.0.iter() {
+        let (x, y) = info.sensor;
+        let reach = x.abs_diff(info.beacon.0) as i64 + y.abs_diff(info.beacon.1) as i64;
+        let fits = |coordinate: i32| {
+            i32::try_from(coordinate as i64 - reach).is_ok()
+                && i32::try_from(coordinate as i64 + reach).is_ok()
+        };
+        if !fits(x) || !fits(y) {
+            return Err(Error::CoordinateOverflow(info.sensor).into());
+        }
+    }
+
+    Ok(sensors)
+}
+
+mod generate;
+pub mod render;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?, 10)?, 26);
+
+        Ok(())
+    }
+
+    #[test]
+    // #[ignore]
+    fn test_part_one() -> Result<()> {
+        assert_eq!(
+            part_one(&parse_input(load::<Day15>(None)?)?, LINE)?,
+            4502208
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?, 20)?, 56000011);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input, 10)?, 26);
+            assert_eq!(part_two(&input, 20)?, 56000011);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        assert_eq!(
+            part_two(&parse_input(load::<Day15>(None)?)?, MAX)?,
+            13784551204480
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_covered_intervals() -> Result<()> {
+        let input = parse_input(TESTDATA)?;
+        assert_eq!(input.covered_intervals(10), [-2..=24]);
+        assert_eq!(input.covered_intervals(11), [-3..=13, 15..=25]);
+        assert_eq!(input.covered_intervals(-10), [2..=2]);
+        assert!(input.covered_intervals(-11).is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_gap() -> Result<()> {
+        let input = parse_input("Sensor at x=2, y=2: closest beacon is at x=2, y=6\n")?;
+        assert!(matches!(part_two(&input, 4), Err(Error::NoGap)));
+        let input = parse_input("Sensor at x=2, y=10: closest beacon is at x=2, y=14\n")?;
+        assert!(matches!(part_two(&input, 4), Err(Error::Ambiguous(25))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_coordinate_overflow() -> Result<()> {
+        for (input, sensor) in [
+            (
+                "Sensor at x=2147483647, y=0: closest beacon is at x=-2147483648, y=0\n",
+                (i32::MAX, 0),
+            ),
+            (
+                "Sensor at x=2000000000, y=2000000000: \
+                 closest beacon is at x=-2000000000, y=-2000000000\n",
+                (2_000_000_000, 2_000_000_000),
+            ),
+        ] {
+            let error = parse_input(input).unwrap_err();
+            assert!(
+                matches!(error.downcast_ref(), Some(Error::CoordinateOverflow(s)) if *s == sensor)
+            );
+        }
+
+        // Reaching right up to the edges is fine.
+        let input = parse_input(
+            "Sensor at x=-2147483638, y=0: closest beacon is at x=-2147483648, y=0\n\
+             Sensor at x=2147483637, y=0: closest beacon is at x=2147483647, y=0\n",
+        )?;
+        assert_eq!(part_one(&input, 0)?, 40);
+        assert_eq!(
+            input.covered_intervals(0),
+            [i32::MIN..=-2147483628, 2147483627..=i32::MAX]
+        );
+        let area = Area {
+            x: i32::MAX - 1..=i32::MAX,
+            y: -1..=1,
+        };
+        let corner = |y| Area {
+            x: i32::MAX..=i32::MAX,
+            y: y..=y,
+        };
+        assert_eq!(input.gaps(&area), Gaps::Many(vec![corner(-1), corner(1)]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gaps() -> Result<()> {
+        let input = parse_input(TESTDATA)?;
+        assert_eq!(input.gaps(&Area::square(20)), Gaps::One((14, 11)));
+        let area = Area {
+            x: 13..=15,
+            y: 10..=12,
+        };
+        assert_eq!(input.gaps(&area), Gaps::One((14, 11)));
+        let area = Area { x: 0..=4, y: 0..=4 };
+        assert_eq!(input.gaps(&area), Gaps::None);
+
+        let input = parse_input("Sensor at x=0, y=0: closest beacon is at x=0, y=1\n")?;
+        let area = Area {
+            x: -1..=1,
+            y: -2..=1,
+        };
+        let corner = |x, y| Area { x: x..=x, y: y..=y };
+        assert_eq!(
+            input.gaps(&area),
+            Gaps::Many(vec![
+                Area {
+                    x: -1..=1,
+                    y: -2..=-2
+                },
+                corner(-1, -1),
+                corner(1, -1),
+                corner(-1, 1),
+                corner(1, 1),
+            ])
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,116 @@
+//! Property tests checking the sensor coverage against a cell-by-cell scan on random sensor
+//! layouts.
+
+use super::*;
+use proptest::prelude::*;
+
+type Report = ((i32, i32), (i32, i32));
+
+fn reports() -> impl Strategy<Value = Vec<Report>> {
+    let position = (-10..30i32, -10..30i32);
+    prop::collection::vec((position.clone(), position), 1..10)
+}
+
+/// Sensors that just miss one position in the square from `(0, 0)` to `(max, max)`, so it is
+/// the only gap as often as not, and the size of that square.
+fn gaps() -> impl Strategy<Value = (Vec<Report>, i32)> {
+    (5..20i32).prop_flat_map(|max| {
+        let sensor = (-2..max + 2, -2..max + 2, 0..4usize);
+        ((0..=max, 0..=max), prop::collection::vec(sensor, 10..40)).prop_map(
+            move |((gap_x, gap_y), sensors)| {
+                let reports = sensors
+                    .into_iter()
+                    .filter(|(x, y, _)| (*x, *y) != (gap_x, gap_y))
+                    .map(|(x, y, side)| {
+                        // The nearest beacon is one step closer than the gap, in any direction.
+                        let reach = (x.abs_diff(gap_x) + y.abs_diff(gap_y)) as i32 - 1;
+                        let beacon = match side {
+                            0 => (x + reach, y),
+                            1 => (x - reach, y),
+                            2 => (x, y + reach),
+                            _ => (x, y - reach),
+                        };
+                        ((x, y), beacon)
+                    })
+                    .collect();
+                (reports, max)
+            },
+        )
+    })
+}
+
+fn render(reports: &[Report]) -> &'static str {
+    reports
+        .iter()
+        .map(|((sx, sy), (bx, by))| {
+            format!("Sensor at x={sx}, y={sy}: closest beacon is at x={bx}, y={by}\n")
+        })
+        .collect::<String>()
+        .leak()
+}
+
+fn covered(reports: &[Report], (x, y): (i32, i32)) -> bool {
+    reports.iter().any(|((sx, sy), (bx, by))| {
+        sx.abs_diff(x) + sy.abs_diff(y) <= sx.abs_diff(*bx) + sy.abs_diff(*by)
+    })
+}
+
+fn area() -> impl Strategy<Value = Area> {
+    (-10..30i32, 0..15i32, -10..30i32, 0..15i32).prop_map(|(x, width, y, height)| Area {
+        x: x..=x + width,
+        y: y..=y + height,
+    })
+}
+
+proptest! {
+    #[test]
+    fn part_one_counts_positions_without_a_beacon(reports in reports(), line in -10..30i32) {
+        let input = parse_input(render(&reports)).unwrap();
+        // No sensor reaches further than 80 from where they all are.
+        let expected = (-100..140)
+            .filter(|x| covered(&reports, (*x, line)))
+            .filter(|x| !reports.iter().any(|(_, beacon)| *beacon == (*x, line)))
+            .count();
+        prop_assert_eq!(part_one(&input, line)?, expected);
+    }
+
+    #[test]
+    fn part_two_finds_the_only_gap((reports, max) in gaps()) {
+        let gaps = (0..=max)
+            .flat_map(|y| (0..=max).map(move |x| (x, y)))
+            .filter(|position| !covered(&reports, *position))
+            .collect::<Vec<(i32, i32)>>();
+        prop_assume!(gaps.len() == 1);
+
+        let input = parse_input(render(&reports)).unwrap();
+        let (x, y) = gaps[0];
+        prop_assert_eq!(part_two(&input, max)?, x as i64 * 4_000_000 + y as i64);
+    }
+
+    #[test]
+    fn gaps_are_the_uncovered_positions(reports in reports(), area in area()) {
+        let expected = area
+            .y
+            .clone()
+            .flat_map(|y| area.x.clone().map(move |x| (x, y)))
+            .filter(|position| !covered(&reports, *position))
+            .collect::<Vec<(i32, i32)>>();
+
+        let input = parse_input(render(&reports)).unwrap();
+        let mut gaps = match input.gaps(&area) {
+            Gaps::None => vec![],
+            Gaps::One(position) => vec![position],
+            Gaps::Many(areas) => {
+                prop_assert!(areas.iter().map(Area::len).sum::<u64>() > 1);
+                areas
+                    .iter()
+                    .flat_map(|gap| {
+                        gap.y.clone().flat_map(|y| gap.x.clone().map(move |x| (x, y)))
+                    })
+                    .collect()
+            }
+        };
+        gaps.sort_unstable_by_key(|(x, y)| (*y, *x));
+        prop_assert_eq!(gaps, expected);
+    }
+}
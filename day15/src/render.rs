@@ -0,0 +1,305 @@
+//! Pictures of a report: the diamond each sensor covers, the beacons, the line part one counts on
+//! and the distress beacon part two finds, scaled to fit and drawn as SVG or PNG.
+
+use anyhow::Result;
+use std::{fmt::Write as _, io::Write};
+
+use crate::{Coord, Sensors};
+
+const BACKGROUND: [u8; 3] = [255, 255, 255];
+const COVERAGE: [u8; 3] = [74, 144, 217];
+const SENSOR: [u8; 3] = [20, 40, 120];
+const BEACON: [u8; 3] = [240, 150, 20];
+const LINE: [u8; 3] = [30, 160, 60];
+const AREA: [u8; 3] = [0, 0, 0];
+const DISTRESS: [u8; 3] = [220, 20, 30];
+
+/// What to draw on top of the sensors, besides the beacons they found.
+#[derive(Clone, Debug, Default)]
+pub struct Marks {
+    /// The line part one counts the positions of.
+    pub line: Option<i32>,
+    /// The square part two searches, from `(0, 0)` to `(max, max)`.
+    pub max: Option<i32>,
+    /// The position no sensor reaches.
+    pub distress: Option<Coord>,
+}
+
+/// Maps puzzle positions onto a picture at most `size` pixels wide and high, with everything
+/// that is drawn in view.
+struct Frame {
+    left: f64,
+    top: f64,
+    /// Pixels per position.
+    scale: f64,
+    width: u32,
+    height: u32,
+}
+
+impl Frame {
+    fn new(sensors: &Sensors, marks: &Marks, size: u32) -> Self {
+        let mut corners = sensors
+            .0
+            .iter()
+            .flat_map(|info| {
+                let (x, y) = info.sensor;
+                let reach = info.manhattan_distance() as i64;
+                [
+                    (x as i64 - reach, y as i64 - reach),
+                    (x as i64 + reach, y as i64 + reach),
+                ]
+            })
+            .chain(marks.max.map(|_| (0, 0)))
+            .chain(marks.max.map(|max| (max as i64, max as i64)))
+            .chain(marks.distress.map(|(x, y)| (x as i64, y as i64)))
+            .collect::<Vec<(i64, i64)>>();
+        if corners.is_empty() {
+            corners.push((0, 0));
+        }
+
+        let left = corners.iter().map(|(x, _)| *x).min().unwrap_or_default();
+        let right = corners.iter().map(|(x, _)| *x).max().unwrap_or_default();
+        let top = corners.iter().map(|(_, y)| *y).min().unwrap_or_default();
+        let bottom = corners.iter().map(|(_, y)| *y).max().unwrap_or_default();
+        // Every position is a cell, so the last ones take up room too.
+        let (across, down) = ((right - left + 1) as f64, (bottom - top + 1) as f64);
+        let scale = size.max(1) as f64 / across.max(down);
+
+        Frame {
+            left: left as f64,
+            top: top as f64,
+            scale,
+            width: ((across * scale).round() as u32).max(1),
+            height: ((down * scale).round() as u32).max(1),
+        }
+    }
+
+    /// The picture's coordinates of the middle of the cell at `(x, y)`.
+    fn pixel(&self, (x, y): Coord) -> (f64, f64) {
+        (
+            (x as f64 - self.left + 0.5) * self.scale,
+            (y as f64 - self.top + 0.5) * self.scale,
+        )
+    }
+
+    /// The position whose cell the pixel at `(column, row)` falls in.
+    fn position(&self, column: u32, row: u32) -> Coord {
+        (
+            (self.left + (column as f64 + 0.5) / self.scale).floor() as i32,
+            (self.top + (row as f64 + 0.5) / self.scale).floor() as i32,
+        )
+    }
+}
+
+/// Draws the report as an SVG document at most `size` pixels wide and high.
+pub fn svg(sensors: &Sensors, marks: &Marks, size: u32) -> String {
+    let frame = Frame::new(sensors, marks, size);
+    let color = |[r, g, b]: [u8; 3]| format!("#{r:02x}{g:02x}{b:02x}");
+    let dot = (frame.scale / 2.0).max(3.0);
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" \
+         viewBox=\"0 0 {0} {1}\">\n",
+        frame.width, frame.height
+    );
+    let _ = writeln!(
+        svg,
+        "<rect width=\"100%\" height=\"100%\" fill=\"{}\"/>",
+        color(BACKGROUND)
+    );
+    for info in sensors.0.iter() {
+        let (x, y) = frame.pixel(info.sensor);
+        let reach = (info.manhattan_distance() as f64 + 0.5) * frame.scale;
+        let _ = writeln!(
+            svg,
+            "<polygon points=\"{x:.1},{:.1} {:.1},{y:.1} {x:.1},{:.1} {:.1},{y:.1}\" \
+             fill=\"{}\" fill-opacity=\"0.3\"/>",
+            y - reach,
+            x + reach,
+            y + reach,
+            x - reach,
+            color(COVERAGE)
+        );
+    }
+    if let Some(max) = marks.max {
+        let (left, top) = frame.pixel((0, 0));
+        let side = (max as f64 + 1.0) * frame.scale;
+        let (left, top) = (left - frame.scale / 2.0, top - frame.scale / 2.0);
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{left:.1}\" y=\"{top:.1}\" width=\"{side:.1}\" height=\"{side:.1}\" \
+             fill=\"none\" stroke=\"{}\"/>",
+            color(AREA)
+        );
+    }
+    if let Some(line) = marks.line {
+        let (_, y) = frame.pixel((0, line));
+        let _ = writeln!(
+            svg,
+            "<line x1=\"0\" y1=\"{y:.1}\" x2=\"{}\" y2=\"{y:.1}\" stroke=\"{}\"/>",
+            frame.width,
+            color(LINE)
+        );
+    }
+    let mut circle = |position, radius: f64, fill| {
+        let (x, y) = frame.pixel(position);
+        let _ = writeln!(
+            svg,
+            "<circle cx=\"{x:.1}\" cy=\"{y:.1}\" r=\"{radius:.1}\" fill=\"{}\"/>",
+            color(fill)
+        );
+    };
+    for info in sensors.0.iter() {
+        circle(info.sensor, dot, SENSOR);
+        circle(info.beacon, dot, BEACON);
+    }
+    if let Some(distress) = marks.distress {
+        circle(distress, dot * 2.0, DISTRESS);
+    }
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+/// Draws the report as a PNG image at most `size` pixels wide and high, shading each pixel by
+/// how many sensors reach it.
+pub fn png(sensors: &Sensors, marks: &Marks, size: u32, writer: impl Write) -> Result<()> {
+    let frame = Frame::new(sensors, marks, size);
+    let mut pixels = vec![0; frame.width as usize * frame.height as usize * 3];
+    let mut paint = |column: i64, row: i64, color: [u8; 3]| {
+        if (0..frame.width as i64).contains(&column) && (0..frame.height as i64).contains(&row) {
+            let start = (row as usize * frame.width as usize + column as usize) * 3;
+            pixels[start..start + 3].copy_from_slice(&color);
+        }
+    };
+
+    for row in 0..frame.height {
+        for column in 0..frame.width {
+            let (x, y) = frame.position(column, row);
+            let reached = sensors
+                .0
+                .iter()
+                .filter(|info| {
+                    info.sensor.0.abs_diff(x) + info.sensor.1.abs_diff(y)
+                        <= info.manhattan_distance()
+                })
+                .count();
+            // Each sensor reaching a position covers up the background a bit more.
+            let opacity = 1.0 - 0.7f64.powi(reached as i32);
+            let color = [0, 1, 2].map(|channel| {
+                let (from, to) = (BACKGROUND[channel] as f64, COVERAGE[channel] as f64);
+                (from + (to - from) * opacity).round() as u8
+            });
+            paint(column as i64, row as i64, color);
+        }
+    }
+
+    if let Some(max) = marks.max {
+        let (left, top) = frame.pixel((0, 0));
+        let (right, bottom) = frame.pixel((max, max));
+        let half = frame.scale / 2.0;
+        let (left, top) = ((left - half) as i64, (top - half) as i64);
+        let (right, bottom) = ((right + half) as i64, (bottom + half) as i64);
+        for column in left..=right {
+            paint(column, top, AREA);
+            paint(column, bottom, AREA);
+        }
+        for row in top..=bottom {
+            paint(left, row, AREA);
+            paint(right, row, AREA);
+        }
+    }
+    if let Some(line) = marks.line {
+        let (_, row) = frame.pixel((0, line));
+        for column in 0..frame.width as i64 {
+            paint(column, row as i64, LINE);
+        }
+    }
+    let mut dot = |position, radius: i64, color| {
+        let (column, row) = frame.pixel(position);
+        let (column, row) = (column as i64, row as i64);
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                paint(column + dx, row + dy, color);
+            }
+        }
+    };
+    let radius = ((frame.scale / 2.0) as i64).max(2);
+    for info in sensors.0.iter() {
+        dot(info.sensor, radius, SENSOR);
+        dot(info.beacon, radius, BEACON);
+    }
+    if let Some(distress) = marks.distress {
+        dot(distress, radius * 2, DISTRESS);
+    }
+
+    let mut encoder = png::Encoder::new(writer, frame.width, frame.height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(&pixels)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    fn marks() -> Marks {
+        Marks {
+            line: Some(10),
+            max: Some(20),
+            distress: Some((14, 11)),
+        }
+    }
+
+    #[test]
+    fn test_svg() -> Result<()> {
+        let sensors = parse_input(TESTDATA)?;
+        let svg = svg(&sensors, &marks(), 400);
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<polygon ").count(), 14);
+        assert_eq!(svg.matches("<circle ").count(), 2 * 14 + 1);
+        assert_eq!(svg.matches("<line ").count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_png() -> Result<()> {
+        let sensors = parse_input(TESTDATA)?;
+        let mut image = vec![];
+        png(&sensors, &marks(), 400, &mut image)?;
+
+        let reader = png::Decoder::new(image.as_slice()).read_info()?;
+        let info = reader.info();
+        assert!(info.width <= 400 && info.height <= 400);
+        assert!(info.width == 400 || info.height == 400);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame() -> Result<()> {
+        let sensors = parse_input(TESTDATA)?;
+        let frame = Frame::new(&sensors, &Marks::default(), 100);
+        let (x, y) = frame.position(0, 0);
+        let corner = frame.position(frame.width - 1, frame.height - 1);
+
+        assert!(sensors.0.iter().all(|info| {
+            let reach = info.manhattan_distance() as i32;
+            info.sensor.0 - reach >= x && info.sensor.1 - reach >= y
+        }));
+        assert!(sensors.0.iter().all(|info| {
+            let reach = info.manhattan_distance() as i32;
+            info.sensor.0 + reach <= corner.0 && info.sensor.1 + reach <= corner.1
+        }));
+
+        Ok(())
+    }
+}
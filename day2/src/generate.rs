@@ -0,0 +1,35 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day2;
+
+impl Generate for Day2 {
+    /// `size` rounds of the strategy guide.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&['A', 'B', 'C']),
+                    rng.pick(&['X', 'Y', 'Z'])
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day2>(Day2::generate(seed, 100).leak())?;
+        }
+
+        Ok(())
+    }
+}
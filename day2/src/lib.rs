@@ -0,0 +1,246 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{lines, parse_all, IResult},
+    Solution,
+};
+use nom::{
+    character::complete::{self, one_of},
+    error::context,
+    sequence::separated_pair,
+    Parser,
+};
+
+pub struct Day2;
+
+/// Why a strategy guide can't be scored.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0:?} is not a valid move")]
+    InvalidMove(char),
+    #[error("{0:?} is not a valid outcome")]
+    InvalidOutcome(char),
+}
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day2.txt"));
+
+    type Input = Vec<(char, char)>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[(char, char)]) -> Result<u32, Error> {
+    Ok(input
+        .iter()
+        .map(|(them, us)| Ok((Move::try_from(*them)?, Move::try_from(*us)?)))
+        .collect::<Result<Vec<(Move, Move)>, Error>>()?
+        .iter()
+        .map(|(them, us)| Move::score(them, us))
+        .sum())
+}
+
+pub fn part_two(input: &[(char, char)]) -> Result<u32, Error> {
+    Ok(input
+        .iter()
+        .map(|(them, us)| {
+            let their_move = Move::try_from(*them)?;
+            let matched_move = Move::match_move(&their_move, Outcome::try_from(*us)?);
+            Ok((their_move, matched_move))
+        })
+        .collect::<Result<Vec<(Move, Move)>, Error>>()?
+        .iter()
+        .map(|(them, us)| Move::score(them, us))
+        .sum())
+}
+
+#[derive(Debug)]
+enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub fn value(&self) -> u32 {
+        match self {
+            Outcome::Lose => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        }
+    }
+}
+
+impl TryFrom<char> for Outcome {
+    type Error = Error;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        let outcome = match value {
+            'X' => Outcome::Lose,
+            'Y' => Outcome::Draw,
+            'Z' => Outcome::Win,
+            _ => return Err(Error::InvalidOutcome(value)),
+        };
+
+        Ok(outcome)
+    }
+}
+
+#[derive(Debug)]
+enum Move {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Move {
+    pub fn value(&self) -> u32 {
+        match self {
+            Move::Rock => 1,
+            Move::Paper => 2,
+            Move::Scissors => 3,
+        }
+    }
+
+    pub fn score(them: &Move, us: &Move) -> u32 {
+        us.value() + Self::outcome(them, us).value()
+    }
+
+    pub fn match_move(them: &Move, outcome: Outcome) -> Move {
+        match (them, outcome) {
+            (Move::Rock, Outcome::Lose) => Move::Scissors,
+            (Move::Rock, Outcome::Draw) => Move::Rock,
+            (Move::Rock, Outcome::Win) => Move::Paper,
+            (Move::Paper, Outcome::Lose) => Move::Rock,
+            (Move::Paper, Outcome::Draw) => Move::Paper,
+            (Move::Paper, Outcome::Win) => Move::Scissors,
+            (Move::Scissors, Outcome::Lose) => Move::Paper,
+            (Move::Scissors, Outcome::Draw) => Move::Scissors,
+            (Move::Scissors, Outcome::Win) => Move::Rock,
+        }
+    }
+
+    fn outcome(them: &Move, us: &Move) -> Outcome {
+        match (them, us) {
+            (Move::Rock, Move::Rock) => Outcome::Draw,
+            (Move::Rock, Move::Paper) => Outcome::Win,
+            (Move::Rock, Move::Scissors) => Outcome::Lose,
+            (Move::Paper, Move::Rock) => Outcome::Lose,
+            (Move::Paper, Move::Paper) => Outcome::Draw,
+            (Move::Paper, Move::Scissors) => Outcome::Win,
+            (Move::Scissors, Move::Rock) => Outcome::Win,
+            (Move::Scissors, Move::Paper) => Outcome::Lose,
+            (Move::Scissors, Move::Scissors) => Outcome::Draw,
+        }
+    }
+}
+
+impl TryFrom<char> for Move {
+    type Error = Error;
+
+    fn try_from(value: char) -> std::result::Result<Self, Self::Error> {
+        let outcome = match value {
+            'A' | 'X' => Move::Rock,
+            'B' | 'Y' => Move::Paper,
+            'C' | 'Z' => Move::Scissors,
+            _ => return Err(Error::InvalidMove(value)),
+        };
+
+        Ok(outcome)
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<(char, char)>> {
+    lines(parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, (char, char)> {
+    context(
+        "a round of the strategy guide",
+        separated_pair(one_of("ABC"), complete::char(' '), one_of("XYZ")),
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<(char, char)>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse(TESTDATA)?.1)?, 15);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day2>(None)?)?;
+        assert_eq!(part_one(&input)?, 10718);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse(TESTDATA)?.1)?, 12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 15);
+            assert_eq!(part_two(&input)?, 12);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day2>(None)?)?;
+        assert_eq!(part_two(&input)?, 14652);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_round() {
+        assert!(matches!(
+            part_one(&[('A', 'W')]),
+            Err(Error::InvalidMove('W'))
+        ));
+        assert!(matches!(
+            part_two(&[('A', 'W')]),
+            Err(Error::InvalidOutcome('W'))
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+//! Property tests checking the scoring against a brute-force version on random strategy guides.
+
+use super::*;
+use proptest::prelude::*;
+
+const SHAPES: [char; 3] = ['A', 'B', 'C'];
+const RESPONSES: [char; 3] = ['X', 'Y', 'Z'];
+
+fn guide() -> impl Strategy<Value = Vec<(usize, usize)>> {
+    prop::collection::vec((0..3usize, 0..3usize), 1..50)
+}
+
+fn render(guide: &[(usize, usize)]) -> &'static str {
+    guide
+        .iter()
+        .map(|(them, us)| format!("{} {}\n", SHAPES[*them], RESPONSES[*us]))
+        .collect::<String>()
+        .leak()
+}
+
+/// The score of a round, from the rules: the shape's score, plus 6 for a win or 3 for a draw.
+/// Shapes are numbered so that every shape beats the one before it.
+fn score(them: usize, us: usize) -> u32 {
+    let outcome = if us == them {
+        3
+    } else if us == (them + 1) % 3 {
+        6
+    } else {
+        0
+    };
+    us as u32 + 1 + outcome
+}
+
+proptest! {
+    #[test]
+    fn part_one_plays_the_listed_shapes(guide in guide()) {
+        let input = parse_input(render(&guide)).unwrap();
+        let expected = guide.iter().map(|(them, us)| score(*them, *us)).sum::<u32>();
+        prop_assert_eq!(part_one(&input)?, expected);
+    }
+
+    #[test]
+    fn part_two_plays_for_the_listed_outcomes(guide in guide()) {
+        let input = parse_input(render(&guide)).unwrap();
+        // Try every shape and keep the one that gives the outcome asked for.
+        let expected = guide
+            .iter()
+            .map(|(them, outcome)| {
+                (0..3)
+                    .map(|us| (us, score(*them, us)))
+                    .find(|(us, score)| (score - *us as u32 - 1) / 3 == *outcome as u32)
+                    .map(|(_, score)| score)
+                    .unwrap()
+            })
+            .sum::<u32>();
+        prop_assert_eq!(part_two(&input)?, expected);
+    }
+}
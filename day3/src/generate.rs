@@ -0,0 +1,60 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day3;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generate for Day3 {
+    /// `size` groups of three elves. Every rucksack has one item type in both compartments and
+    /// every group has one badge, like the puzzle describes.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let mut output = String::new();
+        for _ in 0..size.max(1) {
+            // The elves of a group pack from separate sets of items plus the badge, so the
+            // badge is the only item all three carry.
+            let mut items = ITEMS.chars().collect::<Vec<char>>();
+            rng.shuffle(&mut items);
+            let (badge, items) = items.split_first().unwrap();
+            for own in items.chunks(items.len() / 3) {
+                output.push_str(&rucksack(&mut rng, own, *badge));
+                output.push('\n');
+            }
+        }
+
+        output
+    }
+}
+
+/// A rucksack packed from `own` items and the `badge`. Its first item type goes in both
+/// compartments and the others are split between them, so it is the only one they share.
+fn rucksack(rng: &mut Rng, own: &[char], badge: char) -> String {
+    let (shared, rest) = own.split_first().unwrap();
+    let (left, right) = rest.split_at(rest.len() / 2);
+    let length = rng.range(2..=16);
+
+    let mut first = vec![*shared, badge];
+    let mut second = vec![*shared];
+    first.extend((first.len()..length).map(|_| *rng.pick(left)));
+    second.extend((second.len()..length).map(|_| *rng.pick(right)));
+    rng.shuffle(&mut first);
+    rng.shuffle(&mut second);
+
+    first.into_iter().chain(second).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day3>(Day3::generate(seed, 30).leak())?;
+        }
+
+        Ok(())
+    }
+}
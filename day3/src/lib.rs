@@ -0,0 +1,158 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{lines, parse_all, IResult},
+    Solution,
+};
+use nom::{character::complete::alpha1, error::context, Parser};
+use std::collections::HashSet;
+
+pub struct Day3;
+
+/// Why the rucksacks have no priorities to add up.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0:?} is not an item")]
+    InvalidItem(char),
+    #[error("The rucksack {0:?} can't be split into two equal compartments")]
+    UnevenRucksack(String),
+    #[error("The last group has {0} elves instead of three")]
+    IncompleteGroup(usize),
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day3.txt"));
+
+    type Input = Vec<&'static str>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[&str]) -> Result<u32, Error> {
+    input
+        .iter()
+        .map(|s| {
+            let (a, b) = s
+                .split_at_checked(s.len() / 2)
+                .filter(|_| s.len() % 2 == 0)
+                .ok_or_else(|| Error::UnevenRucksack(s.to_string()))?;
+            get_sum(a, &[b])
+        })
+        .sum::<Result<u32, Error>>()
+}
+
+pub fn part_two(input: &[&str]) -> Result<u32, Error> {
+    input
+        .chunks(3)
+        .map(|lines| match lines {
+            [first, rest @ ..] if rest.len() == 2 => get_sum(first, rest),
+            _ => Err(Error::IncompleteGroup(lines.len())),
+        })
+        .sum::<Result<u32, Error>>()
+}
+
+fn get_sum(a: &str, rest: &[&str]) -> Result<u32, Error> {
+    a.chars()
+        .collect::<HashSet<char>>()
+        .iter()
+        .filter(|a_char| rest.iter().all(|r| r.contains(**a_char)))
+        .map(|c| get_value(*c))
+        .sum::<Result<u32, Error>>()
+}
+
+fn get_value(c: char) -> Result<u32, Error> {
+    match c {
+        'a'..='z' => Ok((c as u32) - ('a' as u32) + 1),
+        'A'..='Z' => Ok((c as u32) - ('A' as u32) + 27),
+        _ => Err(Error::InvalidItem(c)),
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<&str>> {
+    lines(parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, &str> {
+    context("a rucksack", alpha1).parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<&'static str>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse(TESTDATA)?.1)?, 157);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day3>(None)?)?;
+        assert_eq!(part_one(&input)?, 7980);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse(TESTDATA)?.1)?, 70);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 157);
+            assert_eq!(part_two(&input)?, 70);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day3>(None)?)?;
+        assert_eq!(part_two(&input)?, 2881);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_rucksacks() {
+        assert!(matches!(part_one(&["abc"]), Err(Error::UnevenRucksack(_))));
+        assert!(matches!(part_one(&["a1a1"]), Err(Error::InvalidItem('1'))));
+        assert!(matches!(
+            part_two(&["ab", "ab"]),
+            Err(Error::IncompleteGroup(2))
+        ));
+    }
+}
@@ -0,0 +1,58 @@
+//! Property tests checking the priorities against a brute-force version on random rucksacks.
+
+use super::*;
+use proptest::prelude::*;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Rucksacks of an even number of items, in whole groups of three.
+fn rucksacks() -> impl Strategy<Value = Vec<String>> {
+    let rucksack = (1..12usize).prop_flat_map(|half| {
+        prop::collection::vec(prop::sample::select(ITEMS.as_bytes()), half * 2)
+            .prop_map(|items| String::from_utf8(items).unwrap())
+    });
+    (1..6usize).prop_flat_map(move |groups| prop::collection::vec(rucksack.clone(), groups * 3))
+}
+
+fn render(rucksacks: &[String]) -> &'static str {
+    rucksacks
+        .iter()
+        .map(|rucksack| format!("{rucksack}\n"))
+        .collect::<String>()
+        .leak()
+}
+
+/// The summed priorities of every item type found in all of `parts`, checking each type in turn.
+fn shared(parts: &[&str]) -> u32 {
+    ITEMS
+        .chars()
+        .zip(1..)
+        .filter(|(item, _)| parts.iter().all(|part| part.contains(*item)))
+        .map(|(_, priority)| priority)
+        .sum()
+}
+
+proptest! {
+    #[test]
+    fn part_one_finds_items_in_both_compartments(rucksacks in rucksacks()) {
+        let input = parse_input(render(&rucksacks)).unwrap();
+        let expected = rucksacks
+            .iter()
+            .map(|rucksack| {
+                let (first, second) = rucksack.split_at(rucksack.len() / 2);
+                shared(&[first, second])
+            })
+            .sum::<u32>();
+        prop_assert_eq!(part_one(&input)?, expected);
+    }
+
+    #[test]
+    fn part_two_finds_items_in_the_whole_group(rucksacks in rucksacks()) {
+        let input = parse_input(render(&rucksacks)).unwrap();
+        let expected = rucksacks
+            .chunks(3)
+            .map(|group| shared(&[&group[0], &group[1], &group[2]]))
+            .sum::<u32>();
+        prop_assert_eq!(part_two(&input)?, expected);
+    }
+}
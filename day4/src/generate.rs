@@ -0,0 +1,33 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day4;
+
+impl Generate for Day4 {
+    /// `size` pairs of elves, each assigned a range of the 99 sections.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+        let mut range = || {
+            let start = rng.range(1..=99);
+            format!("{start}-{}", rng.range(start..=99))
+        };
+
+        (0..size.max(1))
+            .map(|_| format!("{},{}\n", range(), range()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day4>(Day4::generate(seed, 100).leak())?;
+        }
+
+        Ok(())
+    }
+}
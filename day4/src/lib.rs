@@ -0,0 +1,177 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{lines, parse_all, unsigned, IResult},
+    Solution,
+};
+use nom::{character::complete, combinator::map, error::context, sequence::separated_pair, Parser};
+use std::ops::RangeInclusive;
+
+pub struct Day4;
+
+/// Why a pair of section assignments can't be compared.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("The range {0:?} ends before it starts")]
+    BackwardsRange(RangeInclusive<u32>),
+}
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day4.txt"));
+
+    type Input = Vec<AssignmentPair>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[AssignmentPair]) -> Result<usize, Error> {
+    check_ranges(input)?;
+
+    Ok(input
+        .iter()
+        .filter(|ap| ap.one_range_envelopes_the_other())
+        .count())
+}
+
+pub fn part_two(input: &[AssignmentPair]) -> Result<usize, Error> {
+    check_ranges(input)?;
+
+    Ok(input
+        .iter()
+        .filter(|ap| ap.one_range_overlaps_the_other())
+        .count())
+}
+
+/// Backwards ranges are empty, which the comparisons below don't allow for.
+fn check_ranges(input: &[AssignmentPair]) -> Result<(), Error> {
+    match input
+        .iter()
+        .flat_map(|ap| [&ap.first, &ap.second])
+        .find(|range| range.is_empty())
+    {
+        Some(range) => Err(Error::BackwardsRange(range.clone())),
+        None => Ok(()),
+    }
+}
+
+pub struct AssignmentPair {
+    first: RangeInclusive<u32>,
+    second: RangeInclusive<u32>,
+}
+
+impl AssignmentPair {
+    pub fn one_range_envelopes_the_other(&self) -> bool {
+        (self.first.contains(self.second.start()) && self.first.contains(self.second.end()))
+            || (self.second.contains(self.first.start()) && self.second.contains(self.first.end()))
+    }
+
+    pub fn one_range_overlaps_the_other(&self) -> bool {
+        !(self.first.start() > self.second.end() || self.first.end() < self.second.start())
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<AssignmentPair>> {
+    lines(parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, AssignmentPair> {
+    context(
+        "a pair of assignments",
+        map(
+            separated_pair(parse_range, complete::char(','), parse_range),
+            |(first, second)| AssignmentPair { first, second },
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_range(input: &str) -> IResult<&str, RangeInclusive<u32>> {
+    context(
+        "a range of sections",
+        map(
+            separated_pair(unsigned, complete::char('-'), unsigned),
+            |(start, end)| start..=end,
+        ),
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<AssignmentPair>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse(TESTDATA)?.1)?, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day4>(None)?)?;
+        assert_eq!(part_one(&input)?, 515);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse(TESTDATA)?.1)?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 2);
+            assert_eq!(part_two(&input)?, 4);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day4>(None)?)?;
+        assert_eq!(part_two(&input)?, 883);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_backwards_range() -> Result<()> {
+        let input = parse_input("2-4,6-8\n5-3,1-9\n")?;
+        assert!(matches!(part_one(&input), Err(Error::BackwardsRange(_))));
+        assert!(matches!(part_two(&input), Err(Error::BackwardsRange(_))));
+
+        Ok(())
+    }
+}
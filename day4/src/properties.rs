@@ -0,0 +1,52 @@
+//! Property tests checking the range comparisons against sets of sections on random assignments.
+
+use super::*;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+type Assignment = (u32, u32);
+
+fn range() -> impl Strategy<Value = Assignment> {
+    (1..100u32, 0..20u32).prop_map(|(start, length)| (start, start + length))
+}
+
+fn pairs() -> impl Strategy<Value = Vec<(Assignment, Assignment)>> {
+    prop::collection::vec((range(), range()), 1..50)
+}
+
+fn render(pairs: &[(Assignment, Assignment)]) -> &'static str {
+    pairs
+        .iter()
+        .map(|((a, b), (c, d))| format!("{a}-{b},{c}-{d}\n"))
+        .collect::<String>()
+        .leak()
+}
+
+fn sections((start, end): Assignment) -> HashSet<u32> {
+    (start..=end).collect()
+}
+
+proptest! {
+    #[test]
+    fn part_one_counts_subsets(pairs in pairs()) {
+        let input = parse_input(render(&pairs)).unwrap();
+        let expected = pairs
+            .iter()
+            .filter(|(first, second)| {
+                let (first, second) = (sections(*first), sections(*second));
+                first.is_subset(&second) || second.is_subset(&first)
+            })
+            .count();
+        prop_assert_eq!(part_one(&input)?, expected);
+    }
+
+    #[test]
+    fn part_two_counts_intersections(pairs in pairs()) {
+        let input = parse_input(render(&pairs)).unwrap();
+        let expected = pairs
+            .iter()
+            .filter(|(first, second)| !sections(*first).is_disjoint(&sections(*second)))
+            .count();
+        prop_assert_eq!(part_two(&input)?, expected);
+    }
+}
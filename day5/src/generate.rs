@@ -0,0 +1,75 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day5;
+
+impl Generate for Day5 {
+    /// Two to nine stacks of crates and `size` rearrangement steps. Steps never take every crate
+    /// off a stack, so all stacks still have a crate on top at the end.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let count = rng.range(2..=9);
+        let mut stacks = (0..count)
+            .map(|_| {
+                (0..rng.range(1..=8))
+                    .map(|_| rng.range(b'A'..=b'Z') as char)
+                    .collect::<Vec<char>>()
+            })
+            .collect::<Vec<Vec<char>>>();
+        // There are more crates than stacks, so some stack always has one to spare.
+        stacks[0].push(rng.range(b'A'..=b'Z') as char);
+
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+        let steps = (0..size.max(1))
+            .map(|_| {
+                let spare = (0..count)
+                    .filter(|i| heights[*i] > 1)
+                    .collect::<Vec<usize>>();
+                let from = *rng.pick(&spare);
+                let to = (from + rng.range(1..=count - 1)) % count;
+                let amount = rng.range(1..=heights[from] - 1);
+                heights[from] -= amount;
+                heights[to] += amount;
+                format!("move {amount} from {} to {}\n", from + 1, to + 1)
+            })
+            .collect::<String>();
+
+        drawing(&stacks) + "\n" + &steps
+    }
+}
+
+/// The stacks as the puzzle draws them, with the stack numbers underneath.
+fn drawing(stacks: &[Vec<char>]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut drawing = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_owned(),
+            })
+            .collect::<Vec<String>>();
+        drawing += &format!("{}\n", row.join(" "));
+    }
+    let numbers = (1..=stacks.len())
+        .map(|n| format!(" {n} "))
+        .collect::<Vec<String>>();
+
+    drawing + &numbers.join(" ") + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day5>(Day5::generate(seed, 100).leak())?;
+        }
+
+        Ok(())
+    }
+}
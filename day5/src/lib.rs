@@ -0,0 +1,312 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{lines, parse_all, unsigned_list, IResult},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, line_ending, one_of, space0, space1},
+    combinator::map,
+    error::context,
+    multi::separated_list1,
+    sequence::{delimited, pair, preceded, separated_pair, terminated},
+    Parser,
+};
+
+pub struct Day5;
+
+/// Why the rearrangement can't be carried out, or leaves no crate on top of a stack.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Step {step} uses stack {stack}, which doesn't exist")]
+    NoSuchStack { step: usize, stack: usize },
+    #[error("Step {step} moves {wanted} crates from stack {stack}, which only has {available}")]
+    NotEnoughCrates {
+        step: usize,
+        stack: usize,
+        wanted: usize,
+        available: usize,
+    },
+    #[error("Stack {0} ends up empty, so it has no crate on top")]
+    EmptyStack(usize),
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day5.txt"));
+
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+    type PartOne = String;
+    type PartTwo = String;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        let (crates, instructions) = input;
+        Ok(part_one(crates, instructions)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        let (crates, instructions) = input;
+        Ok(part_two(crates, instructions)?)
+    }
+}
+
+pub fn part_one(crates: &[Vec<char>], instructions: &[Instruction]) -> Result<String, Error> {
+    let mut crates = crates.to_vec();
+    for (i, instruction) in instructions.iter().enumerate() {
+        // Moving the crates one at a time turns the lifted ones upside down.
+        let buf = take(&mut crates, i + 1, instruction)?;
+        stack(&mut crates, i + 1, instruction.to)?.extend(buf.into_iter().rev());
+    }
+
+    tops(&crates)
+}
+
+pub fn part_two(crates: &[Vec<char>], instructions: &[Instruction]) -> Result<String, Error> {
+    let mut crates = crates.to_vec();
+    for (i, instruction) in instructions.iter().enumerate() {
+        let buf = take(&mut crates, i + 1, instruction)?;
+        stack(&mut crates, i + 1, instruction.to)?.extend(buf);
+    }
+
+    tops(&crates)
+}
+
+/// Lifts the crates `instruction` moves off the top of their stack, keeping their order.
+fn take(
+    crates: &mut [Vec<char>],
+    step: usize,
+    instruction: &Instruction,
+) -> Result<Vec<char>, Error> {
+    let from = stack(crates, step, instruction.from)?;
+    let idx = from
+        .len()
+        .checked_sub(instruction.amount)
+        .ok_or(Error::NotEnoughCrates {
+            step,
+            stack: instruction.from,
+            wanted: instruction.amount,
+            available: from.len(),
+        })?;
+
+    Ok(from.split_off(idx))
+}
+
+/// The stack with the 1-based `number` the puzzle uses.
+fn stack(crates: &mut [Vec<char>], step: usize, number: usize) -> Result<&mut Vec<char>, Error> {
+    number
+        .checked_sub(1)
+        .and_then(|i| crates.get_mut(i))
+        .ok_or(Error::NoSuchStack {
+            step,
+            stack: number,
+        })
+}
+
+fn tops(crates: &[Vec<char>]) -> Result<String, Error> {
+    crates
+        .iter()
+        .enumerate()
+        .map(|(i, cr)| cr.last().copied().ok_or(Error::EmptyStack(i + 1)))
+        .collect::<Result<String, Error>>()
+}
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+impl From<(u8, u8, u8)> for Instruction {
+    fn from(tuple: (u8, u8, u8)) -> Self {
+        Instruction {
+            amount: tuple.0 as usize,
+            from: tuple.1 as usize,
+            to: tuple.2 as usize,
+        }
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, (Vec<Vec<char>>, Vec<Instruction>)> {
+    separated_pair(
+        parse_crates,
+        pair(line_ending, line_ending),
+        parse_instructions,
+    )
+    .parse(input)
+}
+
+fn parse_instructions(input: &str) -> IResult<&str, Vec<Instruction>> {
+    lines(parse_instruction).parse(input)
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    context(
+        "a rearrangement step",
+        map(
+            (
+                preceded(tag("move "), complete::u8),
+                preceded(tag(" from "), complete::u8),
+                preceded(tag(" to "), complete::u8),
+            ),
+            Instruction::from,
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_crates(input: &str) -> IResult<&str, Vec<Vec<char>>> {
+    map(
+        pair(terminated(parse_crate_lines, line_ending), parse_index_line),
+        |(lines, numbers)| transpose(lines, numbers.len()),
+    )
+    .parse(input)
+}
+
+/// Turns the rows of crates into `count` stacks, bottom crate first. Rows lose their trailing
+/// empty crates when their whitespace is trimmed, so they can be shorter than `count`.
+fn transpose(lines: Vec<Vec<Option<char>>>, count: usize) -> Vec<Vec<char>> {
+    let mut output = (0..count.max(lines.iter().map(Vec::len).max().unwrap_or(0)))
+        .map(|_| vec![])
+        .collect::<Vec<Vec<char>>>();
+    lines.iter().rev().for_each(|line| {
+        line.iter().enumerate().for_each(|(i, cr)| {
+            if let Some(c) = cr {
+                output[i].push(*c);
+            }
+        })
+    });
+    output
+}
+
+fn parse_crate_lines(input: &str) -> IResult<&str, Vec<Vec<Option<char>>>> {
+    lines(terminated(parse_crate_line, space0)).parse(input)
+}
+
+fn parse_index_line(input: &str) -> IResult<&str, Vec<u8>> {
+    context("the stack numbers", preceded(space0, unsigned_list(space1))).parse(input)
+}
+
+fn parse_crate_line(input: &str) -> IResult<&str, Vec<Option<char>>> {
+    context(
+        "a row of crates",
+        separated_list1(
+            complete::char(' '),
+            map(alt((parse_crate, parse_empty_crate)), |a| {
+                if a == ' ' {
+                    None
+                } else {
+                    Some(a)
+                }
+            }),
+        ),
+    )
+    .parse(input)
+}
+
+fn parse_empty_crate(input: &str) -> IResult<&str, char> {
+    delimited(
+        complete::char(' '),
+        complete::char(' '),
+        complete::char(' '),
+    )
+    .parse(input)
+}
+
+fn parse_crate(input: &str) -> IResult<&str, char> {
+    delimited(
+        complete::char('['),
+        one_of("ABCDEFGHIJKLMNOPQRSTUVWXYZ"),
+        complete::char(']'),
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<(Vec<Vec<char>>, Vec<Instruction>)> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        let (crates, instructions) = parse(TESTDATA)?.1;
+        assert_eq!("CMZ", part_one(&crates, &instructions)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let (crates, instructions) = parse_input(load::<Day5>(None)?)?;
+        assert_eq!("FJSRQCFTN", part_one(&crates, &instructions)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        let (crates, instructions) = parse(TESTDATA)?.1;
+        assert_eq!("MCD", part_two(&crates, &instructions)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let (crates, instructions) = parse_input(input)?;
+            assert_eq!(part_one(&crates, &instructions)?, "CMZ");
+            assert_eq!(part_two(&crates, &instructions)?, "MCD");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let (crates, instructions) = parse_input(load::<Day5>(None)?)?;
+        assert_eq!("CJVLJQPHS", part_two(&crates, &instructions)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_steps() {
+        let crates = vec![vec!['A'], vec!['B']];
+        let step = |amount, from, to| [Instruction { amount, from, to }];
+        assert!(matches!(
+            part_one(&crates, &step(2, 1, 2)),
+            Err(Error::NotEnoughCrates {
+                step: 1,
+                stack: 1,
+                wanted: 2,
+                available: 1
+            })
+        ));
+        assert!(matches!(
+            part_two(&crates, &step(1, 1, 3)),
+            Err(Error::NoSuchStack { step: 1, stack: 3 })
+        ));
+        assert!(matches!(
+            part_one(&crates, &step(1, 1, 2)),
+            Err(Error::EmptyStack(1))
+        ));
+    }
+}
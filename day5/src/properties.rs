@@ -0,0 +1,92 @@
+//! Property tests checking the crane against a crate-by-crate simulation on random stacks and
+//! legal rearrangements.
+
+use super::*;
+use proptest::prelude::*;
+
+type Step = (usize, usize, usize);
+
+/// Up to nine stacks of crates and a list of steps that never take more crates than a stack has.
+fn procedures() -> impl Strategy<Value = (Vec<Vec<char>>, Vec<Step>)> {
+    let stack = prop::collection::vec(prop::char::range('A', 'Z'), 0..7);
+    let stacks = prop::collection::vec(stack, 1..10).prop_filter("no crates at all", |stacks| {
+        stacks.iter().any(|s| !s.is_empty())
+    });
+    let choices = prop::collection::vec((any::<usize>(), any::<usize>(), any::<usize>()), 1..20);
+
+    (stacks, choices).prop_map(|(stacks, choices)| {
+        let mut heights = stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+        let steps = choices
+            .into_iter()
+            .map(|(from, to, amount)| {
+                let non_empty = (0..heights.len())
+                    .filter(|i| heights[*i] > 0)
+                    .collect::<Vec<usize>>();
+                let from = non_empty[from % non_empty.len()];
+                let to = to % heights.len();
+                let amount = amount % heights[from] + 1;
+                heights[from] -= amount;
+                heights[to] += amount;
+                (amount, from + 1, to + 1)
+            })
+            .collect();
+        (stacks, steps)
+    })
+}
+
+/// The drawing of the stacks with the procedure underneath, as the puzzle shows them.
+fn render(stacks: &[Vec<char>], steps: &[Step]) -> &'static str {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or(0);
+    let mut text = String::new();
+    for level in (0..height).rev() {
+        let row = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{c}]"),
+                None => "   ".to_owned(),
+            })
+            .collect::<Vec<String>>();
+        text += &format!("{}\n", row.join(" "));
+    }
+    let numbers = (1..=stacks.len())
+        .map(|n| format!(" {n} "))
+        .collect::<Vec<String>>();
+    text += &format!("{}\n\n", numbers.join(" "));
+    for (amount, from, to) in steps {
+        text += &format!("move {amount} from {from} to {to}\n");
+    }
+
+    text.leak()
+}
+
+/// Carries out the steps, lifting the crates one at a time or all at once, and reads the tops.
+fn simulate(stacks: &[Vec<char>], steps: &[Step], all_at_once: bool) -> Option<String> {
+    let mut stacks = stacks.to_vec();
+    for (amount, from, to) in steps {
+        let mut lifted = vec![];
+        for _ in 0..*amount {
+            lifted.push(stacks[from - 1].pop()?);
+        }
+        if all_at_once {
+            lifted.reverse();
+        }
+        stacks[to - 1].extend(lifted);
+    }
+
+    stacks.iter().map(|stack| stack.last()).collect()
+}
+
+proptest! {
+    #[test]
+    fn part_one_moves_one_crate_at_a_time((stacks, steps) in procedures()) {
+        let (crates, instructions) = parse_input(render(&stacks, &steps)).unwrap();
+        prop_assert_eq!(&crates, &stacks);
+        prop_assert_eq!(part_one(&crates, &instructions).ok(), simulate(&stacks, &steps, false));
+    }
+
+    #[test]
+    fn part_two_moves_crates_all_at_once((stacks, steps) in procedures()) {
+        let (crates, instructions) = parse_input(render(&stacks, &steps)).unwrap();
+        prop_assert_eq!(part_two(&crates, &instructions).ok(), simulate(&stacks, &steps, true));
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day6;
+
+impl Generate for Day6 {
+    /// A datastream of `size` characters, or the 14 a start-of-message marker needs. The marker
+    /// is hidden at a random position among letters from a small alphabet, so it's likely the
+    /// first one.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let mut stream = (0..size.max(14))
+            .map(|_| rng.range(b'a'..=b'h'))
+            .collect::<Vec<u8>>();
+        let mut marker = (b'a'..=b'z').collect::<Vec<u8>>();
+        rng.shuffle(&mut marker);
+        let at = rng.range(0..=stream.len() - 14);
+        stream[at..at + 14].copy_from_slice(&marker[..14]);
+
+        String::from_utf8(stream).unwrap() + "\n"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day6>(Day6::generate(seed, 1000).leak())?;
+        }
+
+        Ok(())
+    }
+}
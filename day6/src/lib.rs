@@ -0,0 +1,132 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{parse_all, IResult},
+    Solution,
+};
+use nom::{character::complete::alpha1, error::context, Parser};
+
+pub struct Day6;
+
+/// Why the datastream has no marker.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("There is no run of {0} different characters")]
+    NoMarker(usize),
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day6.txt"));
+
+    type Input = &'static str;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &str) -> Result<usize, Error> {
+    get_marker(input, 4)
+}
+
+pub fn part_two(input: &str) -> Result<usize, Error> {
+    get_marker(input, 14)
+}
+
+fn get_marker(input: &str, size: usize) -> Result<usize, Error> {
+    input
+        .as_bytes()
+        .windows(size)
+        .position(|chars| !(1..size).any(|i| chars[..i].contains(&chars[i])))
+        .map(|position| position + size)
+        .ok_or(Error::NoMarker(size))
+}
+
+fn parse(input: &str) -> IResult<&str, &str> {
+    context("a datastream", alpha1).parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<&'static str> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+    use test_case::test_case;
+
+    #[test_case(7, "mjqjpqmgbljsphdztnvjfqwrcgsmlb")]
+    #[test_case(5, "bvwbjplbgvbhsrlpgdmjqwftvncz")]
+    #[test_case(6, "nppdvjthqldpwncqszvftbrmjlhg")]
+    #[test_case(10, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")]
+    #[test_case(11, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")]
+    fn test_part_one_testdata(expected: usize, input: &str) -> Result<()> {
+        assert_eq!(expected, part_one(input)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day6>(None)?)?;
+        assert_eq!(part_one(input)?, 1134);
+
+        Ok(())
+    }
+
+    #[test_case(19, "mjqjpqmgbljsphdztnvjfqwrcgsmlb")]
+    #[test_case(23, "bvwbjplbgvbhsrlpgdmjqwftvncz")]
+    #[test_case(23, "nppdvjthqldpwncqszvftbrmjlhg")]
+    #[test_case(29, "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg")]
+    #[test_case(26, "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw")]
+    fn test_part_two_testdata(expected: usize, input: &str) -> Result<()> {
+        assert_eq!(expected, part_two(input)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day6>(None)?)?;
+        assert_eq!(part_two(input)?, 2263);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants("mjqjpqmgbljsphdztnvjfqwrcgsmlb\n") {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(input)?, 7);
+            assert_eq!(part_two(input)?, 19);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert!(matches!(part_one("abcabc"), Err(Error::NoMarker(4))));
+        assert!(matches!(
+            part_two("abcdefghijklm"),
+            Err(Error::NoMarker(14))
+        ));
+    }
+}
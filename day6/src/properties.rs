@@ -0,0 +1,34 @@
+//! Property tests checking the marker search against a brute-force version on random datastreams.
+
+use super::*;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+/// Datastreams over alphabets small enough that markers are often missing or late.
+fn datastreams() -> impl Strategy<Value = String> {
+    (4..27u8).prop_flat_map(|letters| {
+        prop::collection::vec(b'a'..b'a' + letters, 1..200)
+            .prop_map(|stream| String::from_utf8(stream).unwrap())
+    })
+}
+
+/// The number of characters read when the last `size` of them first all differ.
+fn marker(stream: &str, size: usize) -> Option<usize> {
+    let chars = stream.chars().collect::<Vec<char>>();
+    (size..=chars.len())
+        .find(|end| chars[end - size..*end].iter().collect::<HashSet<_>>().len() == size)
+}
+
+proptest! {
+    #[test]
+    fn part_one_finds_the_packet_marker(stream in datastreams()) {
+        let input = parse_input(format!("{stream}\n").leak()).unwrap();
+        prop_assert_eq!(part_one(input).ok(), marker(&stream, 4));
+    }
+
+    #[test]
+    fn part_two_finds_the_message_marker(stream in datastreams()) {
+        let input = parse_input(format!("{stream}\n").leak()).unwrap();
+        prop_assert_eq!(part_two(input).ok(), marker(&stream, 14));
+    }
+}
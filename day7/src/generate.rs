@@ -0,0 +1,103 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::{Day7, MINIMUM_NEEDED, TOTAL_SIZE};
+
+const FILE_NAMES: [&str; 8] = ["b.txt", "c.dat", "f", "g", "h.lst", "i", "j", "k.log"];
+
+impl Generate for Day7 {
+    /// A terminal session exploring `size` directories. The files fill the disk enough that
+    /// some directory has to be deleted to make room for the update.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        let count = size.max(1);
+        let mut dirs = vec![vec![]; count];
+        for dir in 1..count {
+            dirs[rng.range(0..=dir - 1)].push(dir);
+        }
+        let mut files = (0..count)
+            .map(|_| {
+                let mut names = FILE_NAMES.to_vec();
+                rng.shuffle(&mut names);
+                names.truncate(rng.range(0..=4));
+                names
+                    .into_iter()
+                    .map(|name| (rng.range(1..=300_000), name))
+                    .collect::<Vec<(u32, &str)>>()
+            })
+            .collect::<Vec<Vec<(u32, &str)>>>();
+
+        let used = files.iter().flatten().map(|(size, _)| size).sum::<u32>();
+        let free = TOTAL_SIZE - MINIMUM_NEEDED;
+        if used <= free {
+            let size = free - used + rng.range(1..=1_000_000);
+            files[0].push((size, "update.bak"));
+        }
+
+        let mut session = "$ cd /\n".to_owned();
+        explore(&mut rng, &dirs, &files, 0, &mut session);
+        session
+    }
+}
+
+/// Lists directory `dir`, then changes into each of its directories in turn to do the same.
+fn explore(
+    rng: &mut Rng,
+    dirs: &[Vec<usize>],
+    files: &[Vec<(u32, &str)>],
+    dir: usize,
+    session: &mut String,
+) {
+    if dirs[dir].is_empty() && files[dir].is_empty() {
+        return;
+    }
+
+    let mut listing = dirs[dir]
+        .iter()
+        .map(|sub| format!("dir {}\n", name(*sub)))
+        .chain(
+            files[dir]
+                .iter()
+                .map(|(size, name)| format!("{size} {name}\n")),
+        )
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut listing);
+    *session += "$ ls\n";
+    *session += &listing.concat();
+
+    for sub in dirs[dir].iter() {
+        *session += &format!("$ cd {}\n", name(*sub));
+        explore(rng, dirs, files, *sub, session);
+        *session += "$ cd ..\n";
+    }
+}
+
+/// A name made of letters for directory `dir`, different for every directory.
+fn name(mut dir: usize) -> String {
+    let mut name = vec![];
+    loop {
+        name.push(b'a' + (dir % 26) as u8);
+        dir /= 26;
+        if dir == 0 {
+            break;
+        }
+    }
+    name.reverse();
+
+    String::from_utf8(name).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day7>(Day7::generate(seed, 100).leak())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,276 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{lines, parse_all, IResult},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_while1},
+    character::complete::{self, alpha1, line_ending},
+    combinator::{map, value},
+    error::context,
+    sequence::{pair, preceded, terminated},
+    Parser,
+};
+use std::collections::HashMap;
+
+const TOTAL_SIZE: u32 = 70_000_000;
+const MINIMUM_NEEDED: u32 = 30_000_000;
+
+pub struct Day7;
+
+/// Why the terminal output doesn't tell which directory to delete.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Command {0} moves up out of the root directory")]
+    AboveRoot(usize),
+    #[error("The root directory was never listed")]
+    NoRoot,
+    #[error("There is already enough free space")]
+    EnoughSpace,
+    #[error("No directory is big enough to free {0} more")]
+    NothingBigEnough(u32),
+    #[error("The files listed by command {0} make a directory too large to keep track of")]
+    SizeOverflow(usize),
+}
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day7.txt"));
+
+    type Input = HashMap<String, u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &HashMap<String, u32>) -> Result<u32, Error> {
+    Ok(input.values().filter(|v| **v <= 100_000).sum::<u32>())
+}
+
+pub fn part_two(input: &HashMap<String, u32>) -> Result<u32, Error> {
+    let used = *input.get("/").ok_or(Error::NoRoot)?;
+    let total_needed = used
+        .saturating_add(MINIMUM_NEEDED)
+        .checked_sub(TOTAL_SIZE)
+        .filter(|needed| *needed > 0)
+        .ok_or(Error::EnoughSpace)?;
+    input
+        .values()
+        .filter(|v| **v >= total_needed)
+        .min()
+        .copied()
+        .ok_or(Error::NothingBigEnough(total_needed))
+}
+
+#[derive(Debug)]
+struct File(u32);
+
+#[derive(Debug)]
+enum Command {
+    Cd(String),
+    Ls(Vec<File>),
+}
+
+#[derive(Clone, Debug)]
+enum LsLine {
+    Dir,
+    File(u32),
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Command>> {
+    lines(parse_command).parse(input)
+}
+
+fn parse_command(input: &str) -> IResult<&str, Command> {
+    context("a command", alt((parse_cd, parse_ls))).parse(input)
+}
+
+fn parse_ls(input: &str) -> IResult<&str, Command> {
+    preceded(pair(tag("$ ls"), line_ending), parse_ls_lines).parse(input)
+}
+
+fn parse_ls_lines(input: &str) -> IResult<&str, Command> {
+    map(
+        lines(context(
+            "a directory listing",
+            alt((parse_ls_dir_line, parse_ls_file_line)),
+        )),
+        |ls_lines| {
+            let files = ls_lines
+                .iter()
+                .filter_map(|ls_line| {
+                    if let LsLine::File(size) = ls_line {
+                        Some(File(*size))
+                    } else {
+                        None
+                    }
+                })
+                .collect::<Vec<File>>();
+            Command::Ls(files)
+        },
+    )
+    .parse(input)
+}
+
+fn parse_ls_dir_line(input: &str) -> IResult<&str, LsLine> {
+    value(LsLine::Dir, preceded(tag("dir "), alpha1)).parse(input)
+}
+
+fn parse_ls_file_line(input: &str) -> IResult<&str, LsLine> {
+    map(
+        terminated(
+            complete::u32,
+            pair(
+                complete::char(' '),
+                take_while1(|c: char| c.is_alphabetic() || c == '.'),
+            ),
+        ),
+        LsLine::File,
+    )
+    .parse(input)
+}
+
+fn parse_cd(input: &str) -> IResult<&str, Command> {
+    map(
+        preceded(
+            tag("$ cd "),
+            take_while1(|c: char| c.is_alphabetic() || c == '.' || c == '/'),
+        ),
+        |s: &str| Command::Cd(s.to_owned()),
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<HashMap<String, u32>> {
+    let commands = parse_all(parse, input)?;
+
+    let mut current_path: Vec<&str> = vec![];
+    let mut dirs: HashMap<String, u32> = HashMap::new();
+    for (i, command) in commands.iter().enumerate() {
+        match command {
+            Command::Cd(dir_name) => match dir_name.as_str() {
+                "/" => current_path.clear(),
+                ".." => {
+                    current_path.pop().ok_or(Error::AboveRoot(i + 1))?;
+                }
+                _ => {
+                    current_path.push(dir_name);
+                }
+            },
+            Command::Ls(files) => {
+                let overflow = || Error::SizeOverflow(i + 1);
+                let curr_size = files
+                    .iter()
+                    .try_fold(0u32, |sum, f| sum.checked_add(f.0))
+                    .ok_or_else(overflow)?;
+
+                let paths =
+                    (0..current_path.len()).map(|i| format!("/{}", current_path[0..=i].join("/")));
+                for curr_path in std::iter::once("/".to_owned()).chain(paths) {
+                    let size = dirs.entry(curr_path).or_insert(0);
+                    *size = size.checked_add(curr_size).ok_or_else(overflow)?;
+                }
+            }
+        }
+    }
+
+    Ok(dirs)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+    const TESTDATA_OVERFLOW: &str = include_str!("test_overflow.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 95437);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day7>(None)?)?;
+        assert_eq!(part_one(&input)?, 1182909);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 24933642);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 95437);
+            assert_eq!(part_two(&input)?, 24933642);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day7>(None)?)?;
+        assert_eq!(part_two(&input)?, 2832508);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_stray_line() {
+        let error = parse_input("$ cd /\n$ ls\n14848514 b.txt\nstray line\n$ cd a\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Invalid input at line 4, column 1: expected the end of the input\nstray line\n^"
+        );
+    }
+
+    #[test]
+    fn test_invalid_sessions() -> Result<()> {
+        let error = parse_input("$ cd /\n$ cd ..\n").unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(Error::AboveRoot(2))));
+
+        let input = parse_input("$ cd a\n")?;
+        assert!(matches!(part_two(&input), Err(Error::NoRoot)));
+
+        let input = parse_input("$ cd /\n$ ls\n10 b.txt\n")?;
+        assert!(matches!(part_two(&input), Err(Error::EnoughSpace)));
+
+        let error = parse_input(TESTDATA_OVERFLOW).unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(Error::SizeOverflow(2))));
+
+        // Listing the same directory again adds its files up again.
+        let error = parse_input("$ cd /\n$ ls\n4000000000 a\n$ ls\n4000000000 a\n").unwrap_err();
+        assert!(matches!(error.downcast_ref(), Some(Error::SizeOverflow(3))));
+
+        Ok(())
+    }
+}
@@ -1,194 +1,6 @@
 use anyhow::Result;
-use nom::{
-    branch::alt,
-    bytes::complete::{tag, take_while1},
-    character::complete::{self, alpha1, line_ending},
-    combinator::{map, value},
-    multi::separated_list1,
-    sequence::{pair, preceded, terminated},
-    IResult, Parser,
-};
-use std::{collections::HashMap, ops::AddAssign};
-
-const DATA: &str = include_str!("input.txt");
-const TOTAL_SIZE: u32 = 70_000_000;
-const MINIMUM_NEEDED: u32 = 30_000_000;
+use day7::Day7;
 
 fn main() -> Result<()> {
-    let (took, result) = took::took(|| parse_input(DATA));
-    println!("Time spent parsing: {}", took);
-    let input = result?;
-
-    let (took, result) = took::took(|| part_one(&input));
-    println!("Result part one: {result}");
-    println!("Time spent: {}", took);
-
-    let (took, result) = took::took(|| part_two(&input));
-    println!("Result part two: {result}");
-    println!("Time spent: {took}");
-
-    Ok(())
-}
-
-fn part_one(input: &HashMap<String, u32>) -> u32 {
-    input
-        .iter()
-        .filter_map(|(_, v)| if v <= &100_000 { Some(v) } else { None })
-        .sum::<u32>()
-}
-
-fn part_two(input: &HashMap<String, u32>) -> u32 {
-    let total_needed = *input.get(&"/".to_owned()).unwrap() + MINIMUM_NEEDED - TOTAL_SIZE;
-    *input
-        .iter()
-        .filter_map(|(_, v)| if v >= &total_needed { Some(v) } else { None })
-        .min()
-        .unwrap()
-}
-
-#[derive(Debug)]
-struct File(u32);
-
-#[derive(Debug)]
-enum Command {
-    Cd(String),
-    Ls(Vec<File>),
-}
-
-#[derive(Clone, Debug)]
-enum LsLine {
-    Dir,
-    File(u32),
-}
-
-fn parse(input: &str) -> IResult<&str, Vec<Command>> {
-    separated_list1(line_ending, parse_command).parse(input)
-}
-
-fn parse_command(input: &str) -> IResult<&str, Command> {
-    alt((parse_cd, parse_ls)).parse(input)
-}
-
-fn parse_ls(input: &str) -> IResult<&str, Command> {
-    preceded(pair(tag("$ ls"), line_ending), parse_ls_lines).parse(input)
-}
-
-fn parse_ls_lines(input: &str) -> IResult<&str, Command> {
-    map(
-        separated_list1(line_ending, alt((parse_ls_dir_line, parse_ls_file_line))),
-        |ls_lines| {
-            let files = ls_lines
-                .iter()
-                .filter_map(|ls_line| {
-                    if let LsLine::File(size) = ls_line {
-                        Some(File(*size))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<Vec<File>>();
-            Command::Ls(files)
-        },
-    )
-    .parse(input)
-}
-
-fn parse_ls_dir_line(input: &str) -> IResult<&str, LsLine> {
-    value(LsLine::Dir, preceded(tag("dir "), alpha1)).parse(input)
-}
-
-fn parse_ls_file_line(input: &str) -> IResult<&str, LsLine> {
-    map(
-        terminated(
-            complete::u32,
-            pair(
-                complete::char(' '),
-                take_while1(|c: char| c.is_alphabetic() || c == '.'),
-            ),
-        ),
-        LsLine::File,
-    )
-    .parse(input)
-}
-
-fn parse_cd(input: &str) -> IResult<&str, Command> {
-    map(
-        preceded(
-            tag("$ cd "),
-            take_while1(|c: char| c.is_alphabetic() || c == '.' || c == '/'),
-        ),
-        |s: &str| Command::Cd(s.to_owned()),
-    )
-    .parse(input)
-}
-
-fn parse_input(input: &'static str) -> Result<HashMap<String, u32>> {
-    let (_, commands) = parse(input)?;
-
-    let mut current_path: Vec<&str> = vec![];
-    let mut dirs: HashMap<String, u32> = HashMap::new();
-    for command in commands.iter() {
-        match command {
-            Command::Cd(dir_name) => match dir_name.as_str() {
-                "/" => current_path.clear(),
-                ".." => {
-                    current_path.pop().unwrap();
-                }
-                _ => {
-                    current_path.push(dir_name);
-                }
-            },
-            Command::Ls(files) => {
-                let curr_size = files.iter().map(|f| f.0).sum::<u32>();
-
-                dirs.entry("/".to_owned())
-                    .or_insert(0)
-                    .add_assign(curr_size);
-
-                (0..current_path.len()).for_each(|i| {
-                    let curr_path = format!("/{}", current_path[0..=i].join("/"));
-                    dirs.entry(curr_path).or_insert(0).add_assign(curr_size);
-                });
-            }
-        }
-    }
-
-    Ok(dirs)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TESTDATA: &str = include_str!("test.txt");
-
-    #[test]
-    fn test_part_one_testdata() -> Result<()> {
-        assert_eq!(part_one(&parse_input(TESTDATA)?), 95437);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_one() -> Result<()> {
-        let input = parse_input(DATA)?;
-        assert_eq!(part_one(&input), 1182909);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two_testdata() -> Result<()> {
-        assert_eq!(part_two(&parse_input(TESTDATA)?), 24933642);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_two() -> Result<()> {
-        let input = parse_input(DATA)?;
-        assert_eq!(part_two(&input), 2832508);
-
-        Ok(())
-    }
+    aoc_core::main::<Day7>()
 }
@@ -0,0 +1,89 @@
+//! Property tests checking the directory sizes against a walk of random file trees, replayed as
+//! terminal sessions.
+
+use super::*;
+use proptest::prelude::*;
+
+const NAMES: [&str; 4] = ["a", "b", "c", "d"];
+
+#[derive(Clone, Debug)]
+struct Dir {
+    files: Vec<u32>,
+    dirs: Vec<Dir>,
+}
+
+fn trees() -> impl Strategy<Value = Dir> {
+    // Mostly small files so some directories stay under the limit of part one, with the odd
+    // large one so the disk sometimes needs space freeing.
+    let files = prop::collection::vec(prop_oneof![4 => 1..50_000u32, 1 => 1..15_000_000u32], 0..4);
+    let leaf = files.clone().prop_map(|files| Dir {
+        files,
+        dirs: vec![],
+    });
+    leaf.prop_recursive(3, 20, 4, move |dir| {
+        (files.clone(), prop::collection::vec(dir, 0..4))
+            .prop_map(|(files, dirs)| Dir { files, dirs })
+    })
+}
+
+/// The commands and output of `cd`ing into and listing every directory, depth first.
+fn session(dir: &Dir, text: &mut String) {
+    if dir.files.is_empty() && dir.dirs.is_empty() {
+        return;
+    }
+    *text += "$ ls\n";
+    for name in NAMES.iter().take(dir.dirs.len()) {
+        *text += &format!("dir {name}\n");
+    }
+    for size in dir.files.iter() {
+        *text += &format!("{size} f.txt\n");
+    }
+    for (name, sub) in NAMES.iter().zip(dir.dirs.iter()) {
+        *text += &format!("$ cd {name}\n");
+        session(sub, text);
+        *text += "$ cd ..\n";
+    }
+}
+
+fn render(root: &Dir) -> &'static str {
+    let mut text = "$ cd /\n".to_owned();
+    session(root, &mut text);
+    text.leak()
+}
+
+/// The total size of `dir`, after adding those of it and all directories in it to `sizes_found`.
+fn sizes(dir: &Dir, sizes_found: &mut Vec<u32>) -> u32 {
+    let size = dir.files.iter().sum::<u32>()
+        + dir
+            .dirs
+            .iter()
+            .map(|sub| sizes(sub, sizes_found))
+            .sum::<u32>();
+    sizes_found.push(size);
+    size
+}
+
+proptest! {
+    #[test]
+    fn part_one_sums_small_directories(root in trees()) {
+        let input = parse_input(render(&root)).unwrap();
+        let mut found = vec![];
+        sizes(&root, &mut found);
+        let expected = found.iter().filter(|size| **size <= 100_000).sum::<u32>();
+        prop_assert_eq!(part_one(&input)?, expected);
+    }
+
+    #[test]
+    fn part_two_frees_enough_space(root in trees()) {
+        let input = parse_input(render(&root)).unwrap();
+        let mut found = vec![];
+        let used = sizes(&root, &mut found);
+        let expected = if used + MINIMUM_NEEDED <= TOTAL_SIZE {
+            None
+        } else {
+            let needed = used + MINIMUM_NEEDED - TOTAL_SIZE;
+            found.into_iter().filter(|size| *size >= needed).min()
+        };
+        prop_assert_eq!(part_two(&input).ok(), expected);
+    }
+}
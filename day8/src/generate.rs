@@ -0,0 +1,35 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day8;
+
+impl Generate for Day8 {
+    /// A square forest `size` trees across.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+        let size = size.max(1);
+
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| char::from(rng.range(b'0'..=b'9')))
+                    .collect::<String>()
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day8>(Day8::generate(seed, 30).leak())?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use anyhow::Result;
+use aoc_core::{
+    grid::{Grid, Point},
+    parse::{char_grid, parse_all},
+    Solution,
+};
+use nom::error::context;
+
+pub struct Day8;
+
+/// Why the tree map has no tree to look at.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("There are no trees")]
+    NoTrees,
+}
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day8.txt"));
+
+    type Input = Grid<u8>;
+    type PartOne = usize;
+    type PartTwo = u32;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &Grid<u8>) -> Result<usize, Error> {
+    Ok(input
+        .iter()
+        .filter(|(point, height)| {
+            lines_of_sight(input, *point)
+                .into_iter()
+                .any(|mut line| line.all(|tree| tree < height))
+        })
+        .count())
+}
+
+pub fn part_two(input: &Grid<u8>) -> Result<u32, Error> {
+    input
+        .iter()
+        .map(|(point, height)| {
+            lines_of_sight(input, point)
+                .into_iter()
+                .map(|line| viewing_distance(line, *height))
+                .product()
+        })
+        .max()
+        .ok_or(Error::NoTrees)
+}
+
+/// The trees seen from `(x, y)` looking left, right, up and down, nearest first.
+fn lines_of_sight(input: &Grid<u8>, (x, y): Point) -> [Box<dyn Iterator<Item = &u8> + '_>; 4] {
+    let row = input.row(y);
+    [
+        Box::new(row[..x].iter().rev()),
+        Box::new(row[x + 1..].iter()),
+        Box::new(input.column(x).take(y).rev()),
+        Box::new(input.column(x).skip(y + 1)),
+    ]
+}
+
+fn viewing_distance<'a>(line: impl Iterator<Item = &'a u8>, height: u8) -> u32 {
+    let mut distance = 0;
+    for tree in line {
+        distance += 1;
+        if *tree >= height {
+            break;
+        }
+    }
+    distance
+}
+
+pub fn parse_input(input: &'static str) -> Result<Grid<u8>> {
+    parse_all(
+        context(
+            "a map of tree heights",
+            char_grid(|c| c.to_digit(10).map(|digit| digit as u8)),
+        ),
+        input,
+    )
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 21);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day8>(None)?)?;
+        assert_eq!(part_one(&input)?, 1807);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 21);
+            assert_eq!(part_two(&input)?, 8);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day8>(None)?)?;
+        assert_eq!(part_two(&input)?, 480000);
+
+        Ok(())
+    }
+}
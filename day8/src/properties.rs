@@ -0,0 +1,69 @@
+//! Property tests checking visibility and scenic scores against a brute-force version on random
+//! forests, including the trees on the edges.
+
+use super::*;
+use proptest::prelude::*;
+
+fn forests() -> impl Strategy<Value = Vec<Vec<u8>>> {
+    (1..8usize, 1..8usize).prop_flat_map(|(width, height)| {
+        prop::collection::vec(prop::collection::vec(0..10u8, width), height)
+    })
+}
+
+fn render(forest: &[Vec<u8>]) -> &'static str {
+    forest
+        .iter()
+        .map(|row| row.iter().map(|tree| format!("{tree}")).collect::<String>() + "\n")
+        .collect::<String>()
+        .leak()
+}
+
+/// The trees seen from `(x, y)` in every direction, nearest first, walking the coordinates.
+fn views(forest: &[Vec<u8>], x: usize, y: usize) -> [Vec<u8>; 4] {
+    let (width, height) = (forest[0].len(), forest.len());
+    [
+        (0..x).rev().map(|i| forest[y][i]).collect(),
+        (x + 1..width).map(|i| forest[y][i]).collect(),
+        (0..y).rev().map(|j| forest[j][x]).collect(),
+        (y + 1..height).map(|j| forest[j][x]).collect(),
+    ]
+}
+
+fn trees(forest: &[Vec<u8>]) -> impl Iterator<Item = (usize, usize, u8)> + '_ {
+    forest
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, tree)| (x, y, *tree)))
+}
+
+proptest! {
+    #[test]
+    fn part_one_counts_visible_trees(forest in forests()) {
+        let input = parse_input(render(&forest)).unwrap();
+        let expected = trees(&forest)
+            .filter(|(x, y, tree)| {
+                views(&forest, *x, *y)
+                    .iter()
+                    .any(|view| view.iter().all(|other| other < tree))
+            })
+            .count();
+        prop_assert_eq!(part_one(&input)?, expected);
+    }
+
+    #[test]
+    fn part_two_finds_the_best_scenic_score(forest in forests()) {
+        let input = parse_input(render(&forest)).unwrap();
+        let expected = trees(&forest)
+            .map(|(x, y, tree)| {
+                views(&forest, x, y)
+                    .iter()
+                    .map(|view| match view.iter().position(|other| *other >= tree) {
+                        Some(blocked) => blocked as u32 + 1,
+                        None => view.len() as u32,
+                    })
+                    .product::<u32>()
+            })
+            .max();
+        prop_assert_eq!(part_two(&input).ok(), expected);
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_core::generate::{Generate, Rng};
+
+use crate::Day9;
+
+impl Generate for Day9 {
+    /// `size` motions of the head of the rope.
+    fn generate(seed: u64, size: usize) -> String {
+        let mut rng = Rng::new(seed);
+
+        (0..size.max(1))
+            .map(|_| {
+                format!(
+                    "{} {}\n",
+                    rng.pick(&['U', 'R', 'D', 'L']),
+                    rng.range(1..=20)
+                )
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_round_trip() -> Result<()> {
+        for seed in 0..20 {
+            aoc_core::run::<Day9>(Day9::generate(seed, 200).leak())?;
+        }
+
+        Ok(())
+    }
+}
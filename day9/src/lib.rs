@@ -0,0 +1,212 @@
+use anyhow::Result;
+use aoc_core::{
+    parse::{lines, parse_all, IResult},
+    Solution,
+};
+use nom::{
+    character::complete::{self, one_of},
+    combinator::map_res,
+    error::context,
+    sequence::separated_pair,
+    Parser,
+};
+use std::collections::HashSet;
+
+pub struct Day9;
+
+/// Why the motions of the rope can't be followed.
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{0:?} is not a valid direction")]
+    InvalidDirection(char),
+}
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    #[cfg(feature = "embed-input")]
+    const EMBEDDED: Option<&'static str> = Some(include_str!("../../inputs/day9.txt"));
+
+    type Input = Vec<Instruction>;
+    type PartOne = usize;
+    type PartTwo = usize;
+
+    fn parse(input: &'static str) -> Result<Self::Input> {
+        parse_input(input)
+    }
+
+    fn part_one(input: &Self::Input) -> Result<Self::PartOne> {
+        Ok(part_one(input)?)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<Self::PartTwo> {
+        Ok(part_two(input)?)
+    }
+}
+
+pub fn part_one(input: &[Instruction]) -> Result<usize, Error> {
+    Ok(run_instructions(input, 2))
+}
+
+pub fn part_two(input: &[Instruction]) -> Result<usize, Error> {
+    Ok(run_instructions(input, 10))
+}
+
+fn run_instructions(instructions: &[Instruction], size: usize) -> usize {
+    let mut visited: HashSet<(isize, isize)> = HashSet::new();
+    let mut body: Vec<(isize, isize)> = vec![(0, 0); size];
+    visited.insert((0, 0));
+
+    for instruction in instructions.iter() {
+        match instruction {
+            Instruction::Up(steps) => {
+                for _ in 0..*steps {
+                    body[0].1 += 1;
+                    visited.insert(move_body(&mut body));
+                }
+            }
+            Instruction::Right(steps) => {
+                for _ in 0..*steps {
+                    body[0].0 += 1;
+                    visited.insert(move_body(&mut body));
+                }
+            }
+            Instruction::Down(steps) => {
+                for _ in 0..*steps {
+                    body[0].1 -= 1;
+                    visited.insert(move_body(&mut body));
+                }
+            }
+            Instruction::Left(steps) => {
+                for _ in 0..*steps {
+                    body[0].0 -= 1;
+                    visited.insert(move_body(&mut body));
+                }
+            }
+        }
+    }
+
+    visited.len()
+}
+
+/// Lets every knot follow the one before it, returning where the tail ends up.
+fn move_body(body: &mut [(isize, isize)]) -> (isize, isize) {
+    let mut previous = body[0];
+    for knot in body[1..].iter_mut() {
+        *knot = move_segment(*knot, &previous);
+        previous = *knot;
+    }
+    previous
+}
+
+fn move_segment(mut next: (isize, isize), previous: &(isize, isize)) -> (isize, isize) {
+    if previous.0.abs_diff(next.0) > 1 || previous.1.abs_diff(next.1) > 1 {
+        next.0 += (previous.0 - next.0).signum();
+        next.1 += (previous.1 - next.1).signum();
+    }
+
+    next
+}
+
+#[derive(Debug)]
+pub enum Instruction {
+    Up(u8),
+    Right(u8),
+    Down(u8),
+    Left(u8),
+}
+
+impl TryFrom<(char, u8)> for Instruction {
+    type Error = Error;
+
+    fn try_from(value: (char, u8)) -> std::result::Result<Self, Self::Error> {
+        let (direction, steps) = value;
+        match direction {
+            'U' => Ok(Instruction::Up(steps)),
+            'R' => Ok(Instruction::Right(steps)),
+            'D' => Ok(Instruction::Down(steps)),
+            'L' => Ok(Instruction::Left(steps)),
+            _ => Err(Error::InvalidDirection(direction)),
+        }
+    }
+}
+
+fn parse(input: &str) -> IResult<&str, Vec<Instruction>> {
+    lines(parse_line).parse(input)
+}
+
+fn parse_line(input: &str) -> IResult<&str, Instruction> {
+    context(
+        "a motion",
+        map_res(
+            separated_pair(one_of("URDL"), complete::char(' '), complete::u8),
+            Instruction::try_from,
+        ),
+    )
+    .parse(input)
+}
+
+pub fn parse_input(input: &'static str) -> Result<Vec<Instruction>> {
+    parse_all(parse, input)
+}
+
+mod generate;
+
+#[cfg(test)]
+mod properties;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::{input::load, parse::variants};
+
+    const TESTDATA: &str = include_str!("test.txt");
+    const TESTDATA2: &str = include_str!("test2.txt");
+
+    #[test]
+    fn test_part_one_testdata() -> Result<()> {
+        assert_eq!(part_one(&parse_input(TESTDATA)?)?, 13);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_one() -> Result<()> {
+        let input = parse_input(load::<Day9>(None)?)?;
+        assert_eq!(part_one(&input)?, 6486);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA)?)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_input_variants() -> Result<()> {
+        for input in variants(TESTDATA) {
+            let input = parse_input(input)?;
+            assert_eq!(part_one(&input)?, 13);
+            assert_eq!(part_two(&input)?, 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two_testdata2() -> Result<()> {
+        assert_eq!(part_two(&parse_input(TESTDATA2)?)?, 36);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_part_two() -> Result<()> {
+        let input = parse_input(load::<Day9>(None)?)?;
+        assert_eq!(part_two(&input)?, 2678);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+//! Property tests checking the rope against a step-by-step simulation on random motions.
+
+use super::*;
+use proptest::prelude::*;
+use std::collections::HashSet;
+
+const DIRECTIONS: [(char, (i32, i32)); 4] =
+    [('U', (0, 1)), ('R', (1, 0)), ('D', (0, -1)), ('L', (-1, 0))];
+
+fn motions() -> impl Strategy<Value = Vec<(usize, u8)>> {
+    prop::collection::vec((0..4usize, 1..10u8), 1..40)
+}
+
+fn render(motions: &[(usize, u8)]) -> &'static str {
+    motions
+        .iter()
+        .map(|(direction, steps)| format!("{} {steps}\n", DIRECTIONS[*direction].0))
+        .collect::<String>()
+        .leak()
+}
+
+/// The number of positions the tail of a rope of `knots` visits, moving each knot that is no
+/// longer touching the one ahead of it one step along each axis towards it.
+fn visited(motions: &[(usize, u8)], knots: usize) -> usize {
+    let mut rope = vec![(0i32, 0i32); knots];
+    let mut visited = HashSet::from([(0, 0)]);
+    for (direction, steps) in motions {
+        let (dx, dy) = DIRECTIONS[*direction].1;
+        for _ in 0..*steps {
+            rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+            for i in 1..knots {
+                let (x, y) = (rope[i - 1].0 - rope[i].0, rope[i - 1].1 - rope[i].1);
+                if x.abs() > 1 || y.abs() > 1 {
+                    rope[i] = (rope[i].0 + x.clamp(-1, 1), rope[i].1 + y.clamp(-1, 1));
+                }
+            }
+            visited.insert(rope[knots - 1]);
+        }
+    }
+
+    visited.len()
+}
+
+proptest! {
+    #[test]
+    fn part_one_follows_a_short_rope(motions in motions()) {
+        let input = parse_input(render(&motions)).unwrap();
+        prop_assert_eq!(part_one(&input)?, visited(&motions, 2));
+    }
+
+    #[test]
+    fn part_two_follows_a_long_rope(motions in motions()) {
+        let input = parse_input(render(&motions)).unwrap();
+        prop_assert_eq!(part_two(&input)?, visited(&motions, 10));
+    }
+}
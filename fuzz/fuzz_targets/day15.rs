@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day15::Day15;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    // Parsers take `'static` input like loaded inputs, so every run leaks its copy.
+    let _ = Day15::parse(input.to_owned().leak());
+});
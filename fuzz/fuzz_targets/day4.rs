@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day4::Day4;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    // Parsers take `'static` input like loaded inputs, so every run leaks its copy.
+    let _ = Day4::parse(input.to_owned().leak());
+});
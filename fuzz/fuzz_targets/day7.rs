@@ -0,0 +1,10 @@
+#![no_main]
+
+use aoc_core::Solution;
+use day7::Day7;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    // Parsers take `'static` input like loaded inputs, so every run leaks its copy.
+    let _ = Day7::parse(input.to_owned().leak());
+});
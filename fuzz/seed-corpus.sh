@@ -0,0 +1,18 @@
+#!/bin/sh
+# Seeds each fuzz target's corpus with its day's example inputs, the `test*.txt` files next to
+# the day's source, so fuzzing starts from inputs that parse. Then fuzz a day with a per-input
+# time limit, so parsers that take too long count as failures:
+#
+#     cargo +nightly fuzz run day13 -- -timeout=1
+set -e
+cd "$(dirname "$0")"
+
+for target in fuzz_targets/*.rs; do
+  day=$(basename "$target" .rs)
+  mkdir -p "corpus/$day"
+  for example in ../"$day"/src/test*.txt; do
+    if [ -f "$example" ]; then
+      cp "$example" "corpus/$day/"
+    fi
+  done
+done